@@ -19,7 +19,7 @@ pub(crate) struct EchoTabel {
 
 impl EchoTabel {
     pub(crate) fn new() -> Self {
-        let ddb_client = DynamoDbClient::new(Region::UsWest1);
+        let ddb_client = DynamoDbClient::new(Region::default());
         EchoTabel { ddb_client }
     }
 
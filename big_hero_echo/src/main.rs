@@ -1,11 +1,28 @@
 use futures::future::join_all;
 use lambda::handler_fn;
+use lazy_static::lazy_static;
 use log::debug;
-use rusoto_core::Region;
-use rusoto_secretsmanager::{GetSecretValueRequest, SecretsManager, SecretsManagerClient};
+use serde::Deserialize;
 use serde_json::{from_str, Value};
 use simple_logger;
-use slevr::{InnerEvent, OuterEvent, SlackApiClient};
+use slevr::blocks::BlocksBuilder;
+use slevr::chat::post_message::ChatMessage;
+use slevr::commands::parser::{Arg, Command, CommandSet, Invocation, ParseError};
+use slevr::commands::SlashCommand;
+use slevr::conversations::open::ConversationsOpen;
+use slevr::events::MessageSubtype;
+use slevr::files::complete_upload_external::FilesCompleteUploadExternal;
+use slevr::files::get_upload_url_external::FilesGetUploadUrlExternal;
+use slevr::forwarded::Forwarded;
+use slevr::response_url::ResponseMessage;
+use slevr::router::{Context, Filter, HandlerError, Router};
+use slevr::secrets::{self, CachedSecrets, SecretProvider};
+use slevr::users::UserDirectory;
+use slevr::views::publish::ViewsPublish;
+use slevr::views::View;
+use slevr::{InnerEvent, OuterEvent, SlackApiClient, UserId};
+use std::io::Cursor;
+use std::sync::{Arc, Mutex};
 use tokio;
 
 mod sns;
@@ -16,9 +33,38 @@ use dynamo::EchoTabel;
 
 type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
 
+/// Events longer than this are attached as a snippet, since Slack cuts long messages short.
+const SNIPPET_OVER: usize = 3000;
+
+/// Resolved by whichever provider `SLEVR_SECRETS` picks, e.g. `SLACK_BOT_TOKEN` for env.
+const BOT_TOKEN: &str = "slack_bot_token";
+
+lazy_static! {
+    /// Kept for the life of the process so warm invocations don't refetch the bot token.
+    static ref SECRETS: CachedSecrets =
+        secrets::from_env().expect("SLEVR_SECRETS should name a secret provider");
+    /// Survives warm invocations too, so names are only looked up once an hour.
+    static ref USERS: UserDirectory = UserDirectory::default();
+    /// One client per container, so each method's rate limit budget spans every event
+    /// instead of starting over with each one.
+    static ref SLACK: Mutex<Option<Arc<SlackApiClient>>> = Mutex::new(None);
+}
+
+/// The shared client, built the first time it's needed since the bot token is fetched
+/// asynchronously.
+async fn slack_client() -> Result<Arc<SlackApiClient>, Error> {
+    if let Some(client) = SLACK.lock().unwrap().as_ref() {
+        return Ok(client.clone());
+    }
+    // Like CachedSecrets, the lock isn't held while fetching.
+    let client = Arc::new(SlackApiClient::new(&SECRETS.secret(BOT_TOKEN).await?));
+    Ok(SLACK.lock().unwrap().get_or_insert(client).clone())
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Error> {
     simple_logger::init_with_level(log::Level::Debug)?;
+    lazy_static::initialize(&SECRETS);
     lambda::run(handler_fn(func)).await?;
     Ok(())
 }
@@ -26,97 +72,312 @@ async fn main() -> Result<(), Error> {
 async fn func(message: SNSMessage) -> Result<String, Error> {
     debug!("{:#?}", message);
 
-    let slack_client = SlackApiClient::new(&{
-        let secrets_manager = SecretsManagerClient::new(Region::UsWest1);
-        secrets_manager
-            .get_secret_value(GetSecretValueRequest {
-                secret_id: "SlackClientSecret08B328AB-55ENGjJh0rOK".to_string(),
-                version_id: None,
-                version_stage: None,
-            })
-            .await?
-            .secret_string
-            .unwrap()
-    });
+    let slack_client = slack_client().await?;
 
-    let echo_tabel = EchoTabel::new();
+    let echo_tabel = Arc::new(EchoTabel::new());
 
     let slack_message_str = &message.records.first().unwrap().sns.message[..];
-    let slack_message = serde_json::from_str::<OuterEvent>(slack_message_str);
-
-    if let Ok(OuterEvent::EventCallback {
-        event:
-            InnerEvent::Message {
-                channel,
-                user,
-                text,
-                channel_type,
-                ..
-            },
-        ..
-    }) = slack_message
-    {
-        match (&channel_type[..], &text[..]) {
-            ("im", "echo all") => {
-                let result = slack_client
-                    .chat_post_message(slevr::chat::post_message::ChatMessage {
-                        channel,
-                        text: match echo_tabel.add_listener(user).await {
-                            Ok(_) => "I\'ll now echo everything to you",
-                            Err(_) => "Got an error",
-                        }
-                        .to_string(),
-                        ..Default::default()
-                    })
-                    .await;
-                debug!("{:?}", result);
-            }
-            ("im", "echo none") => {
-                // remove user from dynamo!
-                let result = slack_client
-                    .chat_post_message(slevr::chat::post_message::ChatMessage {
-                        channel,
-                        text: match echo_tabel.remove_listener(user).await {
-                            Ok(_) => "You have been unsubscribed",
-                            Err(_) => "Couldn't remove you",
-                        }
-                        .to_string(),
-                        ..Default::default()
-                    })
-                    .await;
-                debug!("{:?}", result);
-            }
-            ("im", "help") => {
-                let result = slack_client
-                    .chat_post_message(slevr::chat::post_message::ChatMessage {
-                        channel,
-                        text: " You can ask me to send you messages. 
-                        I can either echo everything to you, by you IMing me `echo all`
-                        or I can send nothing to you, if you IM me `echo none`."
-                            .to_string(),
-                        ..Default::default()
-                    })
-                    .await;
-                debug!("{:?}", result);
+    let (slack_message, val) = match classify(slack_message_str)? {
+        Incoming::SlashCommand(command) => {
+            return slash_command(&slack_client, command, &echo_tabel).await;
+        }
+        Incoming::Event(slack_message, val) => (slack_message, Arc::new(val)),
+        Incoming::EchoOnly(val) => {
+            let val = Arc::new(val);
+            if let Err(err) = echo_to_listeners(slack_client, None, echo_tabel, val).await {
+                debug!("{:?}", err);
             }
-            _ => (),
+            return Ok("Success".into());
+        }
+    };
+
+    let router = Router::new(slack_client)
+        .on_any(|_, event| async move {
+            USERS.observe(&event);
+            Ok(())
+        })
+        .on(Filter::event("message").channel_type("im"), {
+            let echo_tabel = echo_tabel.clone();
+            move |ctx, event| dm_command(ctx, event, echo_tabel.clone())
+        })
+        .on_event("app_home_opened", publish_home)
+        .on_any(move |ctx, _| {
+            echo_to_listeners(ctx.client, ctx.user, echo_tabel.clone(), val.clone())
+        });
+
+    if let Err(errors) = router.dispatch(slack_message).await {
+        for err in errors {
+            debug!("{:?}", err);
         }
     }
-    let val: Value = from_str(slack_message_str).unwrap();
-    let messages = echo_tabel
-        .get_listeners()
-        .await
+
+    Ok("Success".into())
+}
+
+/// What point6 forwarded to us.
+#[derive(Debug)]
+enum Incoming {
+    SlashCommand(SlashCommand),
+    /// An event to route, along with the JSON it came from.
+    Event(OuterEvent, Value),
+    /// Interactions, and anything that isn't an `OuterEvent`. There's nothing to route,
+    /// but listeners still want to see them.
+    EchoOnly(Value),
+}
+
+fn classify(slack_message_str: &str) -> Result<Incoming, Error> {
+    let raw: Value = from_str(slack_message_str)?;
+    if Forwarded::is_wrapped(&raw) {
+        return Ok(match Forwarded::deserialize(&raw)? {
+            Forwarded::SlashCommand(command) => Incoming::SlashCommand(command),
+            Forwarded::Interaction(payload) => Incoming::EchoOnly(payload),
+        });
+    }
+    let slack_message: Result<OuterEvent, _> = Deserialize::deserialize(&raw);
+    Ok(match slack_message {
+        Ok(slack_message) => Incoming::Event(slack_message, raw),
+        Err(err) => {
+            debug!("Couldn't parse the event, only echoing it - {}", err);
+            Incoming::EchoOnly(raw)
+        }
+    })
+}
+
+lazy_static! {
+    static ref COMMANDS: CommandSet =
+        CommandSet::new().command(
+            Command::new("echo", "Send you every event I get, or stop sending them").arg(
+                Arg::choice("mode", &["all", "none"], "`all` to start, `none` to stop")
+            )
+        );
+}
+
+/// Runs a parsed command for `user`, returning what to tell them.
+async fn run_command(
+    parsed: Result<Invocation, ParseError>,
+    user: UserId,
+    echo_tabel: &EchoTabel,
+) -> String {
+    match parsed {
+        Ok(Invocation::Help(command)) => COMMANDS.help(command.as_deref()),
+        Ok(Invocation::Run(parsed)) => match parsed.str("mode") {
+            Some("all") => echo_all(user, echo_tabel).await,
+            _ => echo_none(user, echo_tabel).await,
+        }
+        .to_string(),
+        Err(err) => err.to_string(),
+    }
+}
+
+/// Answers commands someone IMs me, as long as it was a person and not a bot. Anything
+/// that doesn't start with a command, like chatting or sharing a file, gets no reply.
+async fn dm_command(
+    ctx: Context,
+    event: Arc<InnerEvent>,
+    echo_tabel: Arc<EchoTabel>,
+) -> Result<(), HandlerError> {
+    match &*event {
+        InnerEvent::Message {
+            subtype: MessageSubtype::Plain(_),
+            ..
+        } => (),
+        _ => return Ok(()),
+    }
+    let (text, user) = match (&ctx.text, &ctx.user) {
+        (Some(text), Some(user)) => (text.clone(), user.clone()),
+        _ => return Ok(()),
+    };
+    let parsed = match COMMANDS.parse(&text) {
+        Err(ParseError::Empty) | Err(ParseError::UnknownCommand(_)) => return Ok(()),
+        parsed => parsed,
+    };
+    let text = run_command(parsed, user, &echo_tabel).await;
+    reply(ctx, text).await
+}
+
+/// Answers `/echo all` and friends privately, through the command's response_url.
+async fn slash_command(
+    client: &SlackApiClient,
+    command: SlashCommand,
+    echo_tabel: &EchoTabel,
+) -> Result<String, Error> {
+    let parsed = COMMANDS.parse(&command.text);
+    let text = run_command(parsed, command.user_id.clone(), echo_tabel).await;
+    client
+        .respond(&command.response_url, &ResponseMessage::ephemeral(text))
+        .await?;
+    Ok("Success".into())
+}
+
+async fn reply(ctx: Context, text: impl Into<String>) -> Result<(), HandlerError> {
+    let result = ctx
+        .client
+        .chat_post_message(ChatMessage {
+            channel: ctx.channel.clone().unwrap_or_default(),
+            text: text.into(),
+            ..Default::default()
+        })
+        .await;
+    debug!("{:?}", result);
+    result?;
+    Ok(())
+}
+
+/// Shows the help on my App Home tab, so people can see what to DM me.
+async fn publish_home(ctx: Context, event: Arc<InnerEvent>) -> Result<(), HandlerError> {
+    let (user, view) = match &*event {
+        InnerEvent::AppHomeOpened {
+            user, tab, view, ..
+        } if tab == "home" => (user.clone(), view),
+        _ => return Ok(()),
+    };
+    let blocks = BlocksBuilder::new()
+        .header("Big Hero Echo")
+        .text(COMMANDS.help(None))
+        .build();
+    ctx.client
+        .views_publish(ViewsPublish {
+            user_id: user,
+            view: View::home(blocks),
+            hash: view
+                .as_ref()
+                .and_then(|view| view["hash"].as_str())
+                .map(str::to_string),
+        })
+        .await?;
+    Ok(())
+}
+
+async fn echo_all(user: UserId, echo_tabel: &EchoTabel) -> &'static str {
+    match echo_tabel.add_listener(user.into()).await {
+        Ok(_) => "I\'ll now echo everything to you",
+        Err(_) => "Got an error",
+    }
+}
+
+async fn echo_none(user: UserId, echo_tabel: &EchoTabel) -> &'static str {
+    // remove user from dynamo!
+    match echo_tabel.remove_listener(user.into()).await {
+        Ok(_) => "You have been unsubscribed",
+        Err(_) => "Couldn't remove you",
+    }
+}
+
+async fn echo_to_listeners(
+    client: Arc<SlackApiClient>,
+    user: Option<UserId>,
+    echo_tabel: Arc<EchoTabel>,
+    val: Arc<Value>,
+) -> Result<(), HandlerError> {
+    let listeners = echo_tabel.get_listeners().await;
+    if listeners.is_empty() {
+        return Ok(());
+    }
+    let from = match &user {
+        Some(user) => format!("From {}:\n", USERS.display_name(&client, user).await),
+        None => String::new(),
+    };
+    let payload = format!("{:#?}", val);
+    if payload.len() > SNIPPET_OVER {
+        return share_snippet(&client, listeners, from, payload).await;
+    }
+    let messages = listeners
         .into_iter()
         .map(|user| {
-            let chat_message = slevr::chat::post_message::ChatMessage {
-                channel: user,
-                text: format!("```{:#?}```", val),
+            let chat_message = ChatMessage {
+                channel: user.into(),
+                text: format!("{}```{}```", from, payload),
                 ..Default::default()
             };
-            slack_client.chat_post_message(chat_message)
+            client.chat_post_message(chat_message)
         })
         .collect::<Vec<_>>();
     join_all(messages).await;
+    Ok(())
+}
 
-    Ok("Success".into())
+/// Shares `payload` as one snippet in each listener's DM with me.
+async fn share_snippet(
+    client: &SlackApiClient,
+    listeners: Vec<String>,
+    from: String,
+    payload: String,
+) -> Result<(), HandlerError> {
+    let opened = listeners.into_iter().map(|user| {
+        client.conversations_open(ConversationsOpen {
+            users: Some(vec![user.into()]),
+            ..Default::default()
+        })
+    });
+    let channels = join_all(opened)
+        .await
+        .into_iter()
+        .filter_map(|opened| opened.ok())
+        .map(|opened| opened.channel.id)
+        .collect::<Vec<_>>();
+    if channels.is_empty() {
+        return Ok(());
+    }
+    client
+        .upload_file(
+            FilesGetUploadUrlExternal {
+                filename: "event.txt".to_string(),
+                length: payload.len() as u64,
+                snippet_type: Some("text".to_string()),
+                ..Default::default()
+            },
+            Cursor::new(payload.into_bytes()),
+            FilesCompleteUploadExternal {
+                channels: Some(channels),
+                initial_comment: Some(from).filter(|from| !from.is_empty()),
+                ..Default::default()
+            },
+        )
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn interactions_are_only_echoed() {
+        let payload = json!({
+            "type": "block_actions",
+            "user": { "id": "UA8RXUSPL", "username": "jtorrance", "team_id": "T9TK3CUKW" },
+            "trigger_id": "12321423423.333649436676.d8c1bb837935619ccad0f624c448ffb3",
+            "actions": [{ "action_id": "WaXA", "block_id": "=qXel", "type": "button" }]
+        });
+        let forwarded = serde_json::to_string(&Forwarded::Interaction(payload.clone())).unwrap();
+        match classify(&forwarded).unwrap() {
+            Incoming::EchoOnly(val) => assert_eq!(payload, val),
+            other => panic!("interaction wasn't echoed - {:?}", other),
+        }
+    }
+
+    #[test]
+    fn event_callbacks_are_routed() {
+        let callback = json!({
+            "token": "XXYYZZ",
+            "team_id": "TXXXXXXXX",
+            "api_app_id": "AXXXXXXXXX",
+            "event": {
+                "type": "reaction_added",
+                "user": "U024BE7LH",
+                "reaction": "thumbsup",
+                "item_user": "U0G9QF9C6",
+                "item": { "type": "message", "channel": "C0G9QF9GZ", "ts": "1360782400.498405" },
+                "event_ts": "1360782804.083113"
+            },
+            "type": "event_callback",
+            "event_id": "Ev08MFMKH6",
+            "event_time": 1234567890
+        });
+        match classify(&callback.to_string()).unwrap() {
+            Incoming::Event(OuterEvent::EventCallback { .. }, val) => assert_eq!(callback, val),
+            other => panic!("event callback wasn't routed - {:?}", other),
+        }
+    }
 }
@@ -16,7 +16,11 @@ export class Point6DeployStack extends cdk.Stack {
     const slackIncomingHandler = new lambda.Function(this, "BigHeroPoint6", {
       runtime: lambda.Runtime.PROVIDED,
       code: lambda.Code.fromAsset("../target/x86_64-unknown-linux-musl/release/slack_incoming_handler.zip"),
-      handler: "ignored"
+      handler: "ignored",
+      environment: {
+        SLEVR_SECRETS: "secrets-manager",
+        SLACK_SIGNING_SECRET_ID: signingSecret.secretArn,
+      }
     })
     signingSecret.grantRead(slackIncomingHandler)
 
@@ -35,7 +39,10 @@ export class Point6DeployStack extends cdk.Stack {
       requestTemplates: { "application/json": '{ "statusCode": "200" }' }
     });
 
-    api.root.addResource("slack").addMethod("POST", slackIntegration);
+    const slackResource = api.root.addResource("slack");
+    slackResource.addMethod("POST", slackIntegration);
+    // Slack's interactivity request URL, handled by the same function
+    slackResource.addResource("interactions").addMethod("POST", slackIntegration);
 
     const messages = new sns.Topic(this, "slack_messages", {
       displayName: "BigHeroPoint6Incoming",
@@ -54,7 +61,11 @@ export class Point6DeployStack extends cdk.Stack {
     const bigHeroEcho = new lambda.Function(this, "BigHeroEcho", {
       runtime: lambda.Runtime.PROVIDED,
       code: lambda.Code.fromAsset("../target/x86_64-unknown-linux-musl/release/big_hero_echo.zip"),
-      handler: "ignored"
+      handler: "ignored",
+      environment: {
+        SLEVR_SECRETS: "secrets-manager",
+        SLACK_BOT_TOKEN_ID: clientSecret.secretArn,
+      }
     })
     clientSecret.grantRead(bigHeroEcho)
     echoTable.grantReadWriteData(bigHeroEcho)
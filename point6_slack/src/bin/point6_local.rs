@@ -0,0 +1,77 @@
+//! Serves the incoming handler over plain HTTP, for pointing ngrok or a test harness at.
+//!
+//! POINT6_ADDR - where to listen, 127.0.0.1:3000 by default
+//! POINT6_SINK - `stdout` (the default) or `file:<path>`
+//! SLEVR_SECRETS - as for the Lambda, defaults to reading SLACK_SIGNING_SECRET
+use big_hero_point6::sink::{FileSink, StdoutSink};
+use big_hero_point6::{handle, CloseOnSubmit, EventSink};
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Request, Response, Server};
+use log::{debug, info};
+use slevr::secrets::{self, CachedSecrets};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+struct State {
+    secrets: CachedSecrets,
+    sink: Box<dyn EventSink>,
+}
+
+fn sink_from_env() -> Result<Box<dyn EventSink>, Error> {
+    let sink = std::env::var("POINT6_SINK").unwrap_or_else(|_| "stdout".to_string());
+    match &sink[..] {
+        "stdout" => Ok(Box::new(StdoutSink)),
+        other if other.starts_with("file:") => Ok(Box::new(FileSink::new(&other["file:".len()..]))),
+        other => Err(format!("Unknown sink {:?}", other).into()),
+    }
+}
+
+async fn serve(state: Arc<State>, request: Request<Body>) -> Result<Response<Body>, Infallible> {
+    let (parts, body) = request.into_parts();
+    let body = match hyper::body::to_bytes(body).await {
+        Ok(body) => body,
+        Err(err) => {
+            debug!("Couldn't read body - {}", err);
+            return Ok(Response::builder().status(400).body(Body::empty()).unwrap());
+        }
+    };
+    let body = match std::str::from_utf8(&body) {
+        Ok(body) => body,
+        Err(_) => return Ok(Response::builder().status(400).body(Body::empty()).unwrap()),
+    };
+
+    let response = handle(
+        &parts.headers,
+        body,
+        &state.secrets,
+        &*state.sink,
+        &CloseOnSubmit,
+    )
+    .await;
+    debug!("Response - {:?}", response);
+    Ok(response.map(Body::from))
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    simple_logger::init_with_level(log::Level::Debug)?;
+    let addr: SocketAddr = std::env::var("POINT6_ADDR")
+        .unwrap_or_else(|_| "127.0.0.1:3000".to_string())
+        .parse()?;
+    let state = Arc::new(State {
+        secrets: secrets::from_env()?,
+        sink: sink_from_env()?,
+    });
+
+    let make_service = make_service_fn(move |_| {
+        let state = state.clone();
+        async move { Ok::<_, Infallible>(service_fn(move |request| serve(state.clone(), request))) }
+    });
+
+    info!("Listening on http://{}", addr);
+    Server::bind(&addr).serve(make_service).await?;
+    Ok(())
+}
@@ -0,0 +1,7 @@
+//! The incoming Slack handler, shared by the Lambda and the local server.
+mod auth;
+pub mod pipeline;
+pub mod sink;
+
+pub use pipeline::{handle, CloseOnSubmit, SubmissionHook};
+pub use sink::EventSink;
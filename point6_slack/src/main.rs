@@ -1,23 +1,29 @@
-mod auth;
-
-use auth::verify_hmac;
+use async_trait::async_trait;
+use big_hero_point6::{handle, CloseOnSubmit, EventSink};
 use http::Response;
 use lambda::handler_fn;
 use lambda_http::{Body, LambdaRequest, LambdaResponse, Request};
+use lazy_static::lazy_static;
 use log::debug;
 use rusoto_core::region::Region;
 use rusoto_sns::{ListTopicsInput, PublishInput, Sns, SnsClient};
-use serde_json;
-use slevr::OuterEvent;
+use slevr::secrets::{self, CachedSecrets};
 use tokio;
 
 use simple_logger;
 
 type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
 
+lazy_static! {
+    /// Kept for the life of the process so warm invocations don't refetch the signing secret.
+    static ref SECRETS: CachedSecrets =
+        secrets::from_env().expect("SLEVR_SECRETS should name a secret provider");
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Error> {
     simple_logger::init_with_level(log::Level::Debug)?;
+    lazy_static::initialize(&SECRETS);
     let func = handler_fn(func);
     lambda::run(func).await?;
     Ok(())
@@ -27,59 +33,14 @@ async fn func(request: LambdaRequest<'_>) -> Result<LambdaResponse, Error> {
     let request: Request = request.into();
     let (parts, body) = request.into_parts();
     let response = if let Body::Text(body) = body {
-        debug!("body - {:?}", body);
-        match (
-            parts.headers.get("X-Slack-Signature"),
-            parts.headers.get("X-Slack-Request-Timestamp"),
-        ) {
-            (Some(sig), Some(ts)) => {
-                debug!("sig - {:?}", sig);
-                debug!("ts - {:?}", ts);
-
-                if let Result::Err(err) =
-                    verify_hmac(ts.to_str().unwrap(), &body[..], sig.to_str().unwrap()).await
-                {
-                    debug!("{}", err);
-                    return Ok(LambdaResponse::from_response(
-                        false,
-                        Response::builder()
-                            .status(403)
-                            .body("".to_string())
-                            .unwrap(),
-                    ));
-                };
-            }
-            _ => {
-                return Ok(LambdaResponse::from_response(
-                    false,
-                    Response::builder()
-                        .status(403)
-                        .body("".to_string())
-                        .unwrap(),
-                ));
-            }
-        }
-
-        let event = serde_json::from_str::<OuterEvent>(&body[..]).unwrap();
-        match event {
-            OuterEvent::UrlVerification { challenge, .. } => {
-                debug!("Got a challenge, responding accordingly?");
-                Response::builder().status(200).body(challenge).unwrap()
-            }
-            _ => {
-                let published = forward_to_sns(&body[..]).await;
-                match published {
-                    Ok(_) => Response::builder()
-                        .status(200)
-                        .body("".to_string())
-                        .unwrap(),
-                    Err(err) => Response::builder()
-                        .status(500)
-                        .body(format!("{:?}", err))
-                        .unwrap(),
-                }
-            }
-        }
+        handle(
+            &parts.headers,
+            &body[..],
+            &*SECRETS,
+            &SnsSink,
+            &CloseOnSubmit,
+        )
+        .await
     } else {
         Response::builder()
             .status(400)
@@ -92,9 +53,19 @@ async fn func(request: LambdaRequest<'_>) -> Result<LambdaResponse, Error> {
     Ok(LambdaResponse::from_response(false, response))
 }
 
+/// Publishes to the slack_incoming_messages topic, where the bots subscribe.
+struct SnsSink;
+
+#[async_trait]
+impl EventSink for SnsSink {
+    async fn forward(&self, body: &str) -> Result<(), String> {
+        forward_to_sns(body).await
+    }
+}
+
 async fn forward_to_sns(body: &str) -> Result<(), String> {
     debug!("Forwarding to SNS: {:?}", body);
-    let sns = SnsClient::new(Region::UsWest1);
+    let sns = SnsClient::new(Region::default());
     let topics = sns.list_topics(ListTopicsInput { next_token: None }).await;
     debug!("Topics - {:?}", topics);
     let topic = topics
@@ -0,0 +1,415 @@
+use crate::auth::signature_verifier;
+use crate::sink::EventSink;
+use http::header::CONTENT_TYPE;
+use http::{HeaderMap, Response};
+use log::debug;
+use serde::Deserialize;
+use serde_json::Value;
+use slevr::commands::{SlashCommand, SlashResponse};
+use slevr::form;
+use slevr::forwarded::Forwarded;
+use slevr::interactions::Interaction;
+use slevr::secrets::SecretProvider;
+use slevr::views::ResponseAction;
+
+fn empty(status: u16) -> Response<String> {
+    Response::builder()
+        .status(status)
+        .body("".to_string())
+        .unwrap()
+}
+
+/// Answers view_submission interactions in the response itself, which is the only way to
+/// show validation errors or change the modal. It runs before Slack gets its answer, so it
+/// has to be quick; Slack gives up after 3 seconds.
+pub trait SubmissionHook: Send + Sync {
+    /// `None` closes the modal. Errors keep it open, and the submission isn't forwarded
+    /// until it's fixed.
+    fn on_submission(&self, submission: &Interaction) -> Option<ResponseAction>;
+}
+
+impl<F> SubmissionHook for F
+where
+    F: Fn(&Interaction) -> Option<ResponseAction> + Send + Sync,
+{
+    fn on_submission(&self, submission: &Interaction) -> Option<ResponseAction> {
+        self(submission)
+    }
+}
+
+/// Closes every modal when it's submitted, leaving the rest to the other end of the sink.
+#[derive(Debug, Default)]
+pub struct CloseOnSubmit;
+
+impl SubmissionHook for CloseOnSubmit {
+    fn on_submission(&self, _: &Interaction) -> Option<ResponseAction> {
+        None
+    }
+}
+
+/// Verifies the request came from Slack, answers url verification challenges and hands
+/// every other event to `sink`. Slash commands and interactions are forwarded wrapped in
+/// a [`Forwarded`], so the receiver can tell them from events. Modal submissions go past
+/// `hook` first.
+pub async fn handle(
+    headers: &HeaderMap,
+    body: &str,
+    secrets: &dyn SecretProvider,
+    sink: &dyn EventSink,
+    hook: &dyn SubmissionHook,
+) -> Response<String> {
+    debug!("body - {:?}", body);
+    let verifier = match signature_verifier(secrets).await {
+        Ok(verifier) => verifier,
+        Err(err) => {
+            debug!("Couldn't load signing secret - {}", err);
+            return empty(500);
+        }
+    };
+    if let Err(err) = verifier.verify_headers(headers, body.as_bytes()) {
+        debug!("Rejecting request - {}", err);
+        return empty(403);
+    }
+
+    let content_type = headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+    if content_type.starts_with(form::CONTENT_TYPE) {
+        let payload = form::decode(body)
+            .ok()
+            .and_then(|fields| fields.into_iter().find(|(name, _)| name == "payload"));
+        match payload {
+            Some((_, payload)) => handle_interaction(&payload, sink, hook).await,
+            None => handle_command(body, sink).await,
+        }
+    } else if content_type.starts_with("application/json") {
+        handle_event(body, sink).await
+    } else {
+        debug!("Unexpected content type {:?}", content_type);
+        empty(415)
+    }
+}
+
+/// Slack needs an answer within 3 seconds, so the command is acknowledged straight away
+/// and whoever is on the other end of the sink replies through its response_url.
+async fn handle_command(body: &str, sink: &dyn EventSink) -> Response<String> {
+    let command = match SlashCommand::from_form(body) {
+        Ok(command) => command,
+        Err(err) => {
+            debug!("Couldn't parse slash command - {}", err);
+            return empty(400);
+        }
+    };
+    let name = command.command.clone();
+    let forwarded =
+        serde_json::to_string(&Forwarded::SlashCommand(command)).expect("slash commands serialize");
+    match sink.forward(&forwarded).await {
+        Ok(_) => empty(200),
+        Err(err) => {
+            debug!("Couldn't forward {} - {:?}", name, err);
+            // A 500 only gets the user a vague "dispatch_failed", so say what happened.
+            let response = SlashResponse::ephemeral(format!(
+                "Sorry, I couldn't run `{}` right now. Please try again.",
+                name
+            ));
+            Response::builder()
+                .status(200)
+                .header(CONTENT_TYPE, "application/json")
+                .body(serde_json::to_string(&response).expect("responses serialize"))
+                .unwrap()
+        }
+    }
+}
+
+/// Interactions are forwarded with the JSON from their `payload` field, so they travel the
+/// same way events do. Modals close once they get the empty 200, unless `hook` answers a
+/// submission with something else.
+async fn handle_interaction(
+    payload: &str,
+    sink: &dyn EventSink,
+    hook: &dyn SubmissionHook,
+) -> Response<String> {
+    let payload = match serde_json::from_str::<Value>(payload) {
+        Ok(payload) => payload,
+        Err(err) => {
+            debug!("Couldn't parse interaction - {:?}", err);
+            return empty(400);
+        }
+    };
+    let interaction = match <Interaction as Deserialize>::deserialize(&payload) {
+        Ok(interaction) => interaction,
+        Err(err) => {
+            debug!("Couldn't parse interaction - {:?}", err);
+            return empty(400);
+        }
+    };
+    debug!("Got {}", interaction.interaction_type());
+    let action = match interaction {
+        Interaction::ViewSubmission { .. } => hook.on_submission(&interaction),
+        _ => None,
+    };
+    if !matches!(action, Some(ResponseAction::Errors { .. })) {
+        let forwarded = serde_json::to_string(&Forwarded::Interaction(payload))
+            .expect("interactions serialize");
+        if let Err(err) = sink.forward(&forwarded).await {
+            return Response::builder()
+                .status(500)
+                .body(format!("{:?}", err))
+                .unwrap();
+        }
+    }
+    match action {
+        Some(action) => Response::builder()
+            .status(200)
+            .header(CONTENT_TYPE, "application/json")
+            .body(serde_json::to_string(&action).expect("response actions serialize"))
+            .unwrap(),
+        None => empty(200),
+    }
+}
+
+/// Only url_verification is answered here. Everything else is forwarded untouched, so an
+/// event slevr models differently from what Slack sent still reaches the bots instead of
+/// being refused until Slack gives up on the subscription.
+async fn handle_event(body: &str, sink: &dyn EventSink) -> Response<String> {
+    let event = match serde_json::from_str::<Value>(body) {
+        Ok(event) => event,
+        Err(err) => {
+            debug!("Couldn't parse event - {:?}", err);
+            return empty(400);
+        }
+    };
+    let r#type = match event.get("type").and_then(Value::as_str) {
+        Some(r#type) => r#type,
+        None => {
+            debug!("Event has no type");
+            return empty(400);
+        }
+    };
+    if r#type == "url_verification" {
+        debug!("Got a challenge, responding accordingly?");
+        return match event.get("challenge").and_then(Value::as_str) {
+            Some(challenge) => Response::builder()
+                .status(200)
+                .body(challenge.to_string())
+                .unwrap(),
+            None => empty(400),
+        };
+    }
+    match sink.forward(body).await {
+        Ok(_) => empty(200),
+        Err(err) => Response::builder()
+            .status(500)
+            .body(format!("{:?}", err))
+            .unwrap(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sink::ChannelSink;
+    use async_trait::async_trait;
+    use slevr::secrets::SecretError;
+    use slevr::verify::{SignatureVerifier, SIGNATURE_HEADER, TIMESTAMP_HEADER};
+    use slevr::views::state::ValidationErrors;
+    use std::time::{SystemTime, UNIX_EPOCH};
+    use tokio::sync::mpsc::unbounded_channel;
+
+    const SECRET: &str = "8f742231b10e8888abcd99yyyzzz85a5";
+
+    struct FixedSecret;
+
+    #[async_trait]
+    impl SecretProvider for FixedSecret {
+        async fn secret(&self, _: &str) -> Result<String, SecretError> {
+            Ok(SECRET.to_string())
+        }
+    }
+
+    fn now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+
+    fn headers(content_type: &str, timestamp: u64, signature: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, content_type.parse().unwrap());
+        headers.insert(TIMESTAMP_HEADER, timestamp.into());
+        headers.insert(SIGNATURE_HEADER, signature.parse().unwrap());
+        headers
+    }
+
+    fn signed(content_type: &str, body: &str) -> HeaderMap {
+        let timestamp = now();
+        let signature =
+            SignatureVerifier::new(SECRET).sign(&timestamp.to_string(), body.as_bytes());
+        headers(content_type, timestamp, &signature)
+    }
+
+    /// Runs a request through `handle`, returning the response and whatever was forwarded.
+    async fn send(headers: HeaderMap, body: &str) -> (Response<String>, Vec<String>) {
+        send_with(headers, body, &CloseOnSubmit).await
+    }
+
+    async fn send_with(
+        headers: HeaderMap,
+        body: &str,
+        hook: &dyn SubmissionHook,
+    ) -> (Response<String>, Vec<String>) {
+        let (sender, mut receiver) = unbounded_channel();
+        let response = handle(
+            &headers,
+            body,
+            &FixedSecret,
+            &ChannelSink::new(sender),
+            hook,
+        )
+        .await;
+        let mut forwarded = vec![];
+        while let Some(body) = receiver.recv().await {
+            forwarded.push(body);
+        }
+        (response, forwarded)
+    }
+
+    const FORM: &str = "application/x-www-form-urlencoded";
+
+    const EVENT: &str = r#"{"token":"XXYYZZ","team_id":"TXXXXXXXX","api_app_id":"AXXXXXXXXX","event":{"type":"app_mention","user":"U061F7AUR","text":"<@U0LAN0Z89> is it everything a river should be?","ts":"1515449522.000016","channel":"C0LAN2Q65","event_ts":"1515449522000016"},"type":"event_callback","event_id":"Ev0LAN670R","event_time":1515449522}"#;
+
+    #[tokio::test]
+    async fn challenges_are_echoed() {
+        let body = r#"{"token":"Jhj5dZrVaK7ZwHHjRyZWjbDl","challenge":"3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P","type":"url_verification"}"#;
+        let (response, forwarded) = send(signed("application/json", body), body).await;
+        assert_eq!(200, response.status());
+        assert_eq!(
+            "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P",
+            response.body()
+        );
+        assert!(forwarded.is_empty());
+    }
+
+    #[tokio::test]
+    async fn bad_signatures_are_forbidden() {
+        let signature = SignatureVerifier::new("not the secret").sign(&now().to_string(), b"{}");
+        let (response, forwarded) =
+            send(headers("application/json", now(), &signature), EVENT).await;
+        assert_eq!(403, response.status());
+        assert!(forwarded.is_empty());
+
+        // Signed properly, but long enough ago to be a replay.
+        let stale = now() - 10 * 60;
+        let signature = SignatureVerifier::new(SECRET).sign(&stale.to_string(), EVENT.as_bytes());
+        let (response, forwarded) =
+            send(headers("application/json", stale, &signature), EVENT).await;
+        assert_eq!(403, response.status());
+        assert!(forwarded.is_empty());
+    }
+
+    #[tokio::test]
+    async fn unparseable_bodies_are_bad_requests() {
+        let body = r#"{"type":"event_callback""#;
+        let (response, forwarded) = send(signed("application/json", body), body).await;
+        assert_eq!(400, response.status());
+        assert!(forwarded.is_empty());
+    }
+
+    #[tokio::test]
+    async fn events_are_forwarded_unchanged() {
+        let (response, forwarded) = send(signed("application/json", EVENT), EVENT).await;
+        assert_eq!(200, response.status());
+        assert_eq!(vec![EVENT.to_string()], forwarded);
+    }
+
+    #[tokio::test]
+    async fn slash_commands_are_forwarded_wrapped() {
+        let body = "token=gIkuvaNzQIHg97ATvDxqgjtO&team_id=T0001&team_domain=example&channel_id=C2147483705&channel_name=test&user_id=U2147483697&user_name=Steve&command=%2Fecho&text=all&response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2F1234%2F5678&trigger_id=13345224609.738474920.8088930838d88f008e0";
+        let (response, forwarded) = send(signed(FORM, body), body).await;
+        assert_eq!(200, response.status());
+        assert_eq!(1, forwarded.len());
+        let raw: Value = serde_json::from_str(&forwarded[0]).unwrap();
+        assert_eq!("slash_command", raw["kind"]);
+        match Forwarded::deserialize(&raw).unwrap() {
+            Forwarded::SlashCommand(command) => {
+                assert_eq!("/echo", command.command);
+                assert_eq!("all", command.text);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn interactions_are_forwarded_wrapped() {
+        let payload = r#"{"type":"shortcut","token":"XXYYZZ","action_ts":"1581106241.371594","team":{"id":"TXXXXXXXX","domain":"shortcuts-test"},"user":{"id":"UXXXXXXXXX","username":"aman","team_id":"TXXXXXXXX"},"callback_id":"shortcut_create_task","trigger_id":"944799105734.773906753841.38b5894552bdd4a780554ee59d1f3638"}"#;
+        let body = form::to_string(&serde_json::json!({ "payload": payload })).unwrap();
+        let (response, forwarded) = send(signed(FORM, &body), &body).await;
+        assert_eq!(200, response.status());
+        assert_eq!(1, forwarded.len());
+        let raw: Value = serde_json::from_str(&forwarded[0]).unwrap();
+        assert_eq!("interaction", raw["kind"]);
+        match Forwarded::deserialize(&raw).unwrap() {
+            Forwarded::Interaction(forwarded) => {
+                assert_eq!(serde_json::from_str::<Value>(payload).unwrap(), forwarded)
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    fn submission(value: &str) -> String {
+        let payload = format!(
+            r#"{{"type":"view_submission","team":{{"id":"T9TK3CUKW","domain":"example"}},"user":{{"id":"UA8RXUSPL","username":"jtorrance","team_id":"T9TK3CUKW"}},"api_app_id":"AAD4NA4NA","token":"9s8d9as89d8as9d8as989","trigger_id":"12466734323.1395872398","view":{{"id":"VNHU13V36","team_id":"T9TK3CUKW","type":"modal","callback_id":"feedback","hash":"156663117.cd33ad1f","state":{{"values":{{"feedback":{{"text":{{"type":"plain_text_input","value":"{}"}}}}}}}}}}}}"#,
+            value
+        );
+        form::to_string(&serde_json::json!({ "payload": payload })).unwrap()
+    }
+
+    /// Wants some feedback before it lets the modal close.
+    fn needs_feedback(submission: &Interaction) -> Option<ResponseAction> {
+        let view = match submission {
+            Interaction::ViewSubmission { view, .. } => view,
+            _ => return None,
+        };
+        if view.state.values["feedback"]["text"]["value"] != "" {
+            return None;
+        }
+        let mut errors = ValidationErrors::new();
+        errors.add("feedback", "Please tell us something");
+        Some(errors.into())
+    }
+
+    #[tokio::test]
+    async fn submission_hook_answers_within_the_response() {
+        let body = submission("");
+        let (response, forwarded) = send_with(signed(FORM, &body), &body, &needs_feedback).await;
+        assert_eq!(200, response.status());
+        assert_eq!("application/json", response.headers()[CONTENT_TYPE]);
+        assert_eq!(
+            serde_json::json!({
+                "response_action": "errors",
+                "errors": { "feedback": "Please tell us something" }
+            }),
+            serde_json::from_str::<Value>(response.body()).unwrap()
+        );
+        // It isn't handed on until it's been fixed.
+        assert!(forwarded.is_empty());
+
+        let body = submission("Great!");
+        let (response, forwarded) = send_with(signed(FORM, &body), &body, &needs_feedback).await;
+        assert_eq!(200, response.status());
+        assert_eq!("", response.body());
+        assert_eq!(1, forwarded.len());
+    }
+
+    #[tokio::test]
+    async fn events_slevr_cant_read_are_still_forwarded() {
+        // app_mention without the user and text slevr expects.
+        let body = r#"{"token":"XXYYZZ","team_id":"TXXXXXXXX","api_app_id":"AXXXXXXXXX","event":{"type":"app_mention","channel":"C0LAN2Q65","event_ts":"1515449522000016","brand_new_field":{"nested":true}},"type":"event_callback","event_id":"Ev0LAN670R","event_time":1515449522}"#;
+        assert!(serde_json::from_str::<slevr::OuterEvent>(body).is_err());
+        let (response, forwarded) = send(signed("application/json", body), body).await;
+        assert_eq!(200, response.status());
+        assert_eq!(vec![body.to_string()], forwarded);
+    }
+}
@@ -0,0 +1,76 @@
+//! Where verified events go once we've acknowledged them. In the Lambda that's SNS.
+use async_trait::async_trait;
+use std::path::PathBuf;
+use tokio::fs::OpenOptions;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc::UnboundedSender;
+
+#[async_trait]
+pub trait EventSink: Send + Sync {
+    /// `body` is the raw event callback, exactly as Slack sent it.
+    async fn forward(&self, body: &str) -> Result<(), String>;
+}
+
+/// Prints each event on its own line.
+#[derive(Debug, Default)]
+pub struct StdoutSink;
+
+#[async_trait]
+impl EventSink for StdoutSink {
+    async fn forward(&self, body: &str) -> Result<(), String> {
+        println!("{}", body);
+        Ok(())
+    }
+}
+
+/// Appends each event to a file as a line of JSON.
+#[derive(Debug)]
+pub struct FileSink {
+    path: PathBuf,
+}
+
+impl FileSink {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        FileSink { path: path.into() }
+    }
+}
+
+#[async_trait]
+impl EventSink for FileSink {
+    async fn forward(&self, body: &str) -> Result<(), String> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .map_err(|err| format!("Couldn't open {} - {}", self.path.display(), err))?;
+        // Events are single line JSON, so one per line keeps the file easy to replay.
+        let write_err = |err| format!("Couldn't write {} - {}", self.path.display(), err);
+        file.write_all(format!("{}\n", body).as_bytes())
+            .await
+            .map_err(write_err)?;
+        // tokio writes in the background, so flush to hear about any errors.
+        file.flush().await.map_err(write_err)
+    }
+}
+
+/// Sends each event down a channel, for driving the handler from tests.
+#[derive(Debug, Clone)]
+pub struct ChannelSink {
+    sender: UnboundedSender<String>,
+}
+
+impl ChannelSink {
+    pub fn new(sender: UnboundedSender<String>) -> Self {
+        ChannelSink { sender }
+    }
+}
+
+#[async_trait]
+impl EventSink for ChannelSink {
+    async fn forward(&self, body: &str) -> Result<(), String> {
+        self.sender
+            .send(body.to_string())
+            .map_err(|_| "Event channel is closed".to_string())
+    }
+}
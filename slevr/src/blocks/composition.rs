@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+
+/// A text object, used almost everywhere a block shows text.
+/// More info - https://api.slack.com/reference/block-kit/composition-objects#text
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Text {
+    PlainText {
+        text: String,
+        /// Indicates whether emojis in a text field should be escaped into the colon emoji format.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        emoji: Option<bool>,
+    },
+    Mrkdwn {
+        text: String,
+        /// When true, URLs won't be auto-converted into links and names won't be linked.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        verbatim: Option<bool>,
+    },
+}
+
+impl Text {
+    pub fn plain(text: impl Into<String>) -> Self {
+        Text::PlainText {
+            text: text.into(),
+            emoji: None,
+        }
+    }
+
+    pub fn mrkdwn(text: impl Into<String>) -> Self {
+        Text::Mrkdwn {
+            text: text.into(),
+            verbatim: None,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        match self {
+            Text::PlainText { text, .. } | Text::Mrkdwn { text, .. } => text,
+        }
+    }
+}
+
+/// A single item in a select menu, overflow menu, checkbox group or radio group.
+/// More info - https://api.slack.com/reference/block-kit/composition-objects#option
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct OptionObject {
+    pub text: Text,
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Text>,
+    /// Only available in overflow menus, loads the URL in the user's browser when clicked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl OptionObject {
+    pub fn new(text: impl Into<String>, value: impl Into<String>) -> Self {
+        OptionObject {
+            text: Text::plain(text),
+            value: value.into(),
+            description: None,
+            url: None,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(Text::plain(description));
+        self
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+}
+
+/// A labelled group of options in a select menu.
+/// More info - https://api.slack.com/reference/block-kit/composition-objects#option_group
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct OptionGroup {
+    pub label: Text,
+    pub options: Vec<OptionObject>,
+}
+
+impl OptionGroup {
+    pub fn new(label: impl Into<String>, options: Vec<OptionObject>) -> Self {
+        OptionGroup {
+            label: Text::plain(label),
+            options,
+        }
+    }
+}
+
+/// An "are you sure?" dialog shown before an interactive element fires.
+/// More info - https://api.slack.com/reference/block-kit/composition-objects#confirm
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ConfirmationDialog {
+    pub title: Text,
+    pub text: Text,
+    pub confirm: Text,
+    pub deny: Text,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<ButtonStyle>,
+}
+
+impl ConfirmationDialog {
+    pub fn new(
+        title: impl Into<String>,
+        text: Text,
+        confirm: impl Into<String>,
+        deny: impl Into<String>,
+    ) -> Self {
+        ConfirmationDialog {
+            title: Text::plain(title),
+            text,
+            confirm: Text::plain(confirm),
+            deny: Text::plain(deny),
+            style: None,
+        }
+    }
+
+    pub fn style(mut self, style: ButtonStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ButtonStyle {
+    Primary,
+    Danger,
+}
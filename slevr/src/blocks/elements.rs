@@ -0,0 +1,665 @@
+use super::composition::{ButtonStyle, ConfirmationDialog, OptionGroup, OptionObject, Text};
+use crate::{ChannelId, UserId};
+use serde::{Deserialize, Serialize};
+
+/// Interactive (and image) elements that can live in section, actions and input blocks.
+/// More info - https://api.slack.com/reference/block-kit/block-elements
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BlockElement {
+    Button(Button),
+    Checkboxes(Checkboxes),
+    Datepicker(Datepicker),
+    Image(ImageElement),
+    Overflow(Overflow),
+    PlainTextInput(PlainTextInput),
+    RadioButtons(RadioButtons),
+    StaticSelect(StaticSelect),
+    MultiStaticSelect(MultiStaticSelect),
+    ExternalSelect(ExternalSelect),
+    UsersSelect(UsersSelect),
+    MultiUsersSelect(MultiUsersSelect),
+    ConversationsSelect(ConversationsSelect),
+    MultiConversationsSelect(MultiConversationsSelect),
+    ChannelsSelect(ChannelsSelect),
+    MultiChannelsSelect(MultiChannelsSelect),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Button {
+    pub text: Text,
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<ButtonStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl Button {
+    pub fn new(text: impl Into<String>, action_id: impl Into<String>) -> Self {
+        Button {
+            text: Text::plain(text),
+            action_id: action_id.into(),
+            url: None,
+            value: None,
+            style: None,
+            confirm: None,
+        }
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    pub fn style(mut self, style: ButtonStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmationDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Checkboxes {
+    pub action_id: String,
+    pub options: Vec<OptionObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_options: Option<Vec<OptionObject>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl Checkboxes {
+    pub fn new(action_id: impl Into<String>, options: Vec<OptionObject>) -> Self {
+        Checkboxes {
+            action_id: action_id.into(),
+            options,
+            initial_options: None,
+            confirm: None,
+        }
+    }
+
+    pub fn initial_options(mut self, initial_options: Vec<OptionObject>) -> Self {
+        self.initial_options = Some(initial_options);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Datepicker {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    /// Formatted as YYYY-MM-DD.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl Datepicker {
+    pub fn new(action_id: impl Into<String>) -> Self {
+        Datepicker {
+            action_id: action_id.into(),
+            placeholder: None,
+            initial_date: None,
+            confirm: None,
+        }
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(Text::plain(placeholder));
+        self
+    }
+
+    pub fn initial_date(mut self, initial_date: impl Into<String>) -> Self {
+        self.initial_date = Some(initial_date.into());
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmationDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ImageElement {
+    pub image_url: String,
+    pub alt_text: String,
+}
+
+impl ImageElement {
+    pub fn new(image_url: impl Into<String>, alt_text: impl Into<String>) -> Self {
+        ImageElement {
+            image_url: image_url.into(),
+            alt_text: alt_text.into(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Overflow {
+    pub action_id: String,
+    /// Between 2 and 5 options.
+    pub options: Vec<OptionObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl Overflow {
+    pub fn new(action_id: impl Into<String>, options: Vec<OptionObject>) -> Self {
+        Overflow {
+            action_id: action_id.into(),
+            options,
+            confirm: None,
+        }
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmationDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+}
+
+/// Only usable inside an input block.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PlainTextInput {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multiline: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u32>,
+}
+
+impl PlainTextInput {
+    pub fn new(action_id: impl Into<String>) -> Self {
+        PlainTextInput {
+            action_id: action_id.into(),
+            placeholder: None,
+            initial_value: None,
+            multiline: None,
+            min_length: None,
+            max_length: None,
+        }
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(Text::plain(placeholder));
+        self
+    }
+
+    pub fn initial_value(mut self, initial_value: impl Into<String>) -> Self {
+        self.initial_value = Some(initial_value.into());
+        self
+    }
+
+    pub fn multiline(mut self, multiline: bool) -> Self {
+        self.multiline = Some(multiline);
+        self
+    }
+
+    pub fn length(mut self, min_length: u32, max_length: u32) -> Self {
+        self.min_length = Some(min_length);
+        self.max_length = Some(max_length);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RadioButtons {
+    pub action_id: String,
+    pub options: Vec<OptionObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_option: Option<OptionObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl RadioButtons {
+    pub fn new(action_id: impl Into<String>, options: Vec<OptionObject>) -> Self {
+        RadioButtons {
+            action_id: action_id.into(),
+            options,
+            initial_option: None,
+            confirm: None,
+        }
+    }
+
+    pub fn initial_option(mut self, initial_option: OptionObject) -> Self {
+        self.initial_option = Some(initial_option);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct StaticSelect {
+    pub placeholder: Text,
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<OptionObject>>,
+    /// Use instead of options to group them under labels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub option_groups: Option<Vec<OptionGroup>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_option: Option<OptionObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl StaticSelect {
+    pub fn new(
+        placeholder: impl Into<String>,
+        action_id: impl Into<String>,
+        options: Vec<OptionObject>,
+    ) -> Self {
+        StaticSelect {
+            placeholder: Text::plain(placeholder),
+            action_id: action_id.into(),
+            options: Some(options),
+            option_groups: None,
+            initial_option: None,
+            confirm: None,
+        }
+    }
+
+    pub fn grouped(
+        placeholder: impl Into<String>,
+        action_id: impl Into<String>,
+        option_groups: Vec<OptionGroup>,
+    ) -> Self {
+        StaticSelect {
+            options: None,
+            option_groups: Some(option_groups),
+            ..StaticSelect::new(placeholder, action_id, Vec::new())
+        }
+    }
+
+    pub fn initial_option(mut self, initial_option: OptionObject) -> Self {
+        self.initial_option = Some(initial_option);
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmationDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MultiStaticSelect {
+    pub placeholder: Text,
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<OptionObject>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub option_groups: Option<Vec<OptionGroup>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_options: Option<Vec<OptionObject>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_selected_items: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl MultiStaticSelect {
+    pub fn new(
+        placeholder: impl Into<String>,
+        action_id: impl Into<String>,
+        options: Vec<OptionObject>,
+    ) -> Self {
+        MultiStaticSelect {
+            placeholder: Text::plain(placeholder),
+            action_id: action_id.into(),
+            options: Some(options),
+            option_groups: None,
+            initial_options: None,
+            max_selected_items: None,
+            confirm: None,
+        }
+    }
+
+    pub fn initial_options(mut self, initial_options: Vec<OptionObject>) -> Self {
+        self.initial_options = Some(initial_options);
+        self
+    }
+
+    pub fn max_selected_items(mut self, max_selected_items: u32) -> Self {
+        self.max_selected_items = Some(max_selected_items);
+        self
+    }
+}
+
+/// Options are loaded from your app's options load URL as the user types.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ExternalSelect {
+    pub placeholder: Text,
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_option: Option<OptionObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_query_length: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl ExternalSelect {
+    pub fn new(placeholder: impl Into<String>, action_id: impl Into<String>) -> Self {
+        ExternalSelect {
+            placeholder: Text::plain(placeholder),
+            action_id: action_id.into(),
+            initial_option: None,
+            min_query_length: None,
+            confirm: None,
+        }
+    }
+
+    pub fn min_query_length(mut self, min_query_length: u32) -> Self {
+        self.min_query_length = Some(min_query_length);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct UsersSelect {
+    pub placeholder: Text,
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_user: Option<UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl UsersSelect {
+    pub fn new(placeholder: impl Into<String>, action_id: impl Into<String>) -> Self {
+        UsersSelect {
+            placeholder: Text::plain(placeholder),
+            action_id: action_id.into(),
+            initial_user: None,
+            confirm: None,
+        }
+    }
+
+    pub fn initial_user(mut self, initial_user: impl Into<UserId>) -> Self {
+        self.initial_user = Some(initial_user.into());
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MultiUsersSelect {
+    pub placeholder: Text,
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_users: Option<Vec<UserId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_selected_items: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl MultiUsersSelect {
+    pub fn new(placeholder: impl Into<String>, action_id: impl Into<String>) -> Self {
+        MultiUsersSelect {
+            placeholder: Text::plain(placeholder),
+            action_id: action_id.into(),
+            initial_users: None,
+            max_selected_items: None,
+            confirm: None,
+        }
+    }
+
+    pub fn initial_users(mut self, initial_users: Vec<UserId>) -> Self {
+        self.initial_users = Some(initial_users);
+        self
+    }
+
+    pub fn max_selected_items(mut self, max_selected_items: u32) -> Self {
+        self.max_selected_items = Some(max_selected_items);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ConversationsSelect {
+    pub placeholder: Text,
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_conversation: Option<ChannelId>,
+    /// Pre-populates the select with the conversation the user was viewing when it opened.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_to_current_conversation: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl ConversationsSelect {
+    pub fn new(placeholder: impl Into<String>, action_id: impl Into<String>) -> Self {
+        ConversationsSelect {
+            placeholder: Text::plain(placeholder),
+            action_id: action_id.into(),
+            initial_conversation: None,
+            default_to_current_conversation: None,
+            confirm: None,
+        }
+    }
+
+    pub fn initial_conversation(mut self, initial_conversation: impl Into<ChannelId>) -> Self {
+        self.initial_conversation = Some(initial_conversation.into());
+        self
+    }
+
+    pub fn default_to_current_conversation(mut self, default: bool) -> Self {
+        self.default_to_current_conversation = Some(default);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MultiConversationsSelect {
+    pub placeholder: Text,
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_conversations: Option<Vec<ChannelId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_selected_items: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl MultiConversationsSelect {
+    pub fn new(placeholder: impl Into<String>, action_id: impl Into<String>) -> Self {
+        MultiConversationsSelect {
+            placeholder: Text::plain(placeholder),
+            action_id: action_id.into(),
+            initial_conversations: None,
+            max_selected_items: None,
+            confirm: None,
+        }
+    }
+
+    pub fn initial_conversations(mut self, initial_conversations: Vec<ChannelId>) -> Self {
+        self.initial_conversations = Some(initial_conversations);
+        self
+    }
+
+    pub fn max_selected_items(mut self, max_selected_items: u32) -> Self {
+        self.max_selected_items = Some(max_selected_items);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ChannelsSelect {
+    pub placeholder: Text,
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_channel: Option<ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl ChannelsSelect {
+    pub fn new(placeholder: impl Into<String>, action_id: impl Into<String>) -> Self {
+        ChannelsSelect {
+            placeholder: Text::plain(placeholder),
+            action_id: action_id.into(),
+            initial_channel: None,
+            confirm: None,
+        }
+    }
+
+    pub fn initial_channel(mut self, initial_channel: impl Into<ChannelId>) -> Self {
+        self.initial_channel = Some(initial_channel.into());
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MultiChannelsSelect {
+    pub placeholder: Text,
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_channels: Option<Vec<ChannelId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_selected_items: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl MultiChannelsSelect {
+    pub fn new(placeholder: impl Into<String>, action_id: impl Into<String>) -> Self {
+        MultiChannelsSelect {
+            placeholder: Text::plain(placeholder),
+            action_id: action_id.into(),
+            initial_channels: None,
+            max_selected_items: None,
+            confirm: None,
+        }
+    }
+
+    pub fn initial_channels(mut self, initial_channels: Vec<ChannelId>) -> Self {
+        self.initial_channels = Some(initial_channels);
+        self
+    }
+
+    pub fn max_selected_items(mut self, max_selected_items: u32) -> Self {
+        self.max_selected_items = Some(max_selected_items);
+        self
+    }
+}
+
+impl From<Button> for BlockElement {
+    fn from(element: Button) -> Self {
+        BlockElement::Button(element)
+    }
+}
+
+impl From<Checkboxes> for BlockElement {
+    fn from(element: Checkboxes) -> Self {
+        BlockElement::Checkboxes(element)
+    }
+}
+
+impl From<Datepicker> for BlockElement {
+    fn from(element: Datepicker) -> Self {
+        BlockElement::Datepicker(element)
+    }
+}
+
+impl From<ImageElement> for BlockElement {
+    fn from(element: ImageElement) -> Self {
+        BlockElement::Image(element)
+    }
+}
+
+impl From<Overflow> for BlockElement {
+    fn from(element: Overflow) -> Self {
+        BlockElement::Overflow(element)
+    }
+}
+
+impl From<PlainTextInput> for BlockElement {
+    fn from(element: PlainTextInput) -> Self {
+        BlockElement::PlainTextInput(element)
+    }
+}
+
+impl From<RadioButtons> for BlockElement {
+    fn from(element: RadioButtons) -> Self {
+        BlockElement::RadioButtons(element)
+    }
+}
+
+impl From<StaticSelect> for BlockElement {
+    fn from(element: StaticSelect) -> Self {
+        BlockElement::StaticSelect(element)
+    }
+}
+
+impl From<MultiStaticSelect> for BlockElement {
+    fn from(element: MultiStaticSelect) -> Self {
+        BlockElement::MultiStaticSelect(element)
+    }
+}
+
+impl From<ExternalSelect> for BlockElement {
+    fn from(element: ExternalSelect) -> Self {
+        BlockElement::ExternalSelect(element)
+    }
+}
+
+impl From<UsersSelect> for BlockElement {
+    fn from(element: UsersSelect) -> Self {
+        BlockElement::UsersSelect(element)
+    }
+}
+
+impl From<MultiUsersSelect> for BlockElement {
+    fn from(element: MultiUsersSelect) -> Self {
+        BlockElement::MultiUsersSelect(element)
+    }
+}
+
+impl From<ConversationsSelect> for BlockElement {
+    fn from(element: ConversationsSelect) -> Self {
+        BlockElement::ConversationsSelect(element)
+    }
+}
+
+impl From<MultiConversationsSelect> for BlockElement {
+    fn from(element: MultiConversationsSelect) -> Self {
+        BlockElement::MultiConversationsSelect(element)
+    }
+}
+
+impl From<ChannelsSelect> for BlockElement {
+    fn from(element: ChannelsSelect) -> Self {
+        BlockElement::ChannelsSelect(element)
+    }
+}
+
+impl From<MultiChannelsSelect> for BlockElement {
+    fn from(element: MultiChannelsSelect) -> Self {
+        BlockElement::MultiChannelsSelect(element)
+    }
+}
@@ -0,0 +1,416 @@
+//! Block Kit layout blocks for building messages, modals and App Home tabs.
+//! More info - https://api.slack.com/reference/block-kit/blocks
+mod composition;
+mod elements;
+
+pub use composition::*;
+pub use elements::*;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Block {
+    Actions(Actions),
+    Context(Context),
+    Divider(Divider),
+    Header(Header),
+    Image(Image),
+    Input(Input),
+    Section(Section),
+}
+
+/// Holds up to 5 interactive elements.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Actions {
+    pub elements: Vec<BlockElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl Actions {
+    pub fn new(elements: Vec<BlockElement>) -> Self {
+        Actions {
+            elements,
+            block_id: None,
+        }
+    }
+
+    pub fn element(mut self, element: impl Into<BlockElement>) -> Self {
+        self.elements.push(element.into());
+        self
+    }
+
+    pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+}
+
+/// Small, muted text and images.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Context {
+    pub elements: Vec<ContextElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl Context {
+    pub fn new(elements: Vec<ContextElement>) -> Self {
+        Context {
+            elements,
+            block_id: None,
+        }
+    }
+
+    pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+}
+
+/// Context blocks only accept text and image elements.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum ContextElement {
+    Text(Text),
+    Image(BlockElement),
+}
+
+impl From<Text> for ContextElement {
+    fn from(text: Text) -> Self {
+        ContextElement::Text(text)
+    }
+}
+
+impl From<ImageElement> for ContextElement {
+    fn from(image: ImageElement) -> Self {
+        ContextElement::Image(BlockElement::Image(image))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct Divider {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+/// Large, bold plain text.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Header {
+    pub text: Text,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl Header {
+    pub fn new(text: impl Into<String>) -> Self {
+        Header {
+            text: Text::plain(text),
+            block_id: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Image {
+    pub image_url: String,
+    pub alt_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl Image {
+    pub fn new(image_url: impl Into<String>, alt_text: impl Into<String>) -> Self {
+        Image {
+            image_url: image_url.into(),
+            alt_text: alt_text.into(),
+            title: None,
+            block_id: None,
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(Text::plain(title));
+        self
+    }
+}
+
+/// Collects information from users, only usable in modals and App Home.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Input {
+    pub label: Text,
+    pub element: BlockElement,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional: Option<bool>,
+    /// Send a block_actions payload as soon as the element changes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dispatch_action: Option<bool>,
+}
+
+impl Input {
+    pub fn new(label: impl Into<String>, element: impl Into<BlockElement>) -> Self {
+        Input {
+            label: Text::plain(label),
+            element: element.into(),
+            block_id: None,
+            hint: None,
+            optional: None,
+            dispatch_action: None,
+        }
+    }
+
+    pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+
+    pub fn hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(Text::plain(hint));
+        self
+    }
+
+    pub fn optional(mut self, optional: bool) -> Self {
+        self.optional = Some(optional);
+        self
+    }
+
+    pub fn dispatch_action(mut self, dispatch_action: bool) -> Self {
+        self.dispatch_action = Some(dispatch_action);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Section {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+    /// Rendered in two columns, up to 10 items.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<Text>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accessory: Option<BlockElement>,
+}
+
+impl Section {
+    pub fn new(text: Text) -> Self {
+        Section {
+            text: Some(text),
+            block_id: None,
+            fields: None,
+            accessory: None,
+        }
+    }
+
+    pub fn fields(fields: Vec<Text>) -> Self {
+        Section {
+            text: None,
+            block_id: None,
+            fields: Some(fields),
+            accessory: None,
+        }
+    }
+
+    pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+
+    pub fn accessory(mut self, accessory: impl Into<BlockElement>) -> Self {
+        self.accessory = Some(accessory.into());
+        self
+    }
+}
+
+impl From<Actions> for Block {
+    fn from(block: Actions) -> Self {
+        Block::Actions(block)
+    }
+}
+
+impl From<Context> for Block {
+    fn from(block: Context) -> Self {
+        Block::Context(block)
+    }
+}
+
+impl From<Divider> for Block {
+    fn from(block: Divider) -> Self {
+        Block::Divider(block)
+    }
+}
+
+impl From<Header> for Block {
+    fn from(block: Header) -> Self {
+        Block::Header(block)
+    }
+}
+
+impl From<Image> for Block {
+    fn from(block: Image) -> Self {
+        Block::Image(block)
+    }
+}
+
+impl From<Input> for Block {
+    fn from(block: Input) -> Self {
+        Block::Input(block)
+    }
+}
+
+impl From<Section> for Block {
+    fn from(block: Section) -> Self {
+        Block::Section(block)
+    }
+}
+
+/// Builds up a list of blocks in display order.
+///
+/// ```
+/// use slevr::blocks::{BlocksBuilder, Button, Section, Text};
+///
+/// let blocks = BlocksBuilder::new()
+///     .header("Echo")
+///     .section(Section::new(Text::mrkdwn("Want me to echo *everything*?")).accessory(Button::new("Yes", "echo_all")))
+///     .divider()
+///     .build();
+/// assert_eq!(3, blocks.len());
+/// ```
+#[derive(Debug, Default)]
+pub struct BlocksBuilder {
+    blocks: Vec<Block>,
+}
+
+impl BlocksBuilder {
+    pub fn new() -> Self {
+        BlocksBuilder::default()
+    }
+
+    pub fn block(mut self, block: impl Into<Block>) -> Self {
+        self.blocks.push(block.into());
+        self
+    }
+
+    pub fn actions(self, elements: Vec<BlockElement>) -> Self {
+        self.block(Actions::new(elements))
+    }
+
+    pub fn context(self, elements: Vec<ContextElement>) -> Self {
+        self.block(Context::new(elements))
+    }
+
+    pub fn divider(self) -> Self {
+        self.block(Divider::default())
+    }
+
+    pub fn header(self, text: impl Into<String>) -> Self {
+        self.block(Header::new(text))
+    }
+
+    pub fn image(self, image_url: impl Into<String>, alt_text: impl Into<String>) -> Self {
+        self.block(Image::new(image_url, alt_text))
+    }
+
+    pub fn input(self, label: impl Into<String>, element: impl Into<BlockElement>) -> Self {
+        self.block(Input::new(label, element))
+    }
+
+    pub fn section(self, section: Section) -> Self {
+        self.block(section)
+    }
+
+    /// Shorthand for a section with just mrkdwn text.
+    pub fn text(self, text: impl Into<String>) -> Self {
+        self.block(Section::new(Text::mrkdwn(text)))
+    }
+
+    pub fn build(self) -> Vec<Block> {
+        self.blocks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn builder_serializes_like_block_kit() {
+        let blocks = BlocksBuilder::new()
+            .header("Reminder")
+            .section(
+                Section::new(Text::mrkdwn("Pick a date for the *deadline*."))
+                    .block_id("deadline")
+                    .accessory(Datepicker::new("pick_date").initial_date("1990-04-28")),
+            )
+            .divider()
+            .context(vec![Text::plain("Sent by echo").into()])
+            .actions(vec![
+                Button::new("Approve", "approve")
+                    .value("yes")
+                    .style(ButtonStyle::Primary)
+                    .into(),
+                Overflow::new(
+                    "more",
+                    vec![
+                        OptionObject::new("Snooze", "snooze"),
+                        OptionObject::new("Drop", "drop"),
+                    ],
+                )
+                .into(),
+            ])
+            .build();
+
+        assert_eq!(
+            json!([
+                {"type": "header", "text": {"type": "plain_text", "text": "Reminder"}},
+                {
+                    "type": "section",
+                    "text": {"type": "mrkdwn", "text": "Pick a date for the *deadline*."},
+                    "block_id": "deadline",
+                    "accessory": {"type": "datepicker", "action_id": "pick_date", "initial_date": "1990-04-28"}
+                },
+                {"type": "divider"},
+                {"type": "context", "elements": [{"type": "plain_text", "text": "Sent by echo"}]},
+                {
+                    "type": "actions",
+                    "elements": [
+                        {
+                            "type": "button",
+                            "text": {"type": "plain_text", "text": "Approve"},
+                            "action_id": "approve",
+                            "value": "yes",
+                            "style": "primary"
+                        },
+                        {
+                            "type": "overflow",
+                            "action_id": "more",
+                            "options": [
+                                {"text": {"type": "plain_text", "text": "Snooze"}, "value": "snooze"},
+                                {"text": {"type": "plain_text", "text": "Drop"}, "value": "drop"}
+                            ]
+                        }
+                    ]
+                }
+            ]),
+            serde_json::to_value(&blocks).unwrap()
+        );
+    }
+
+    #[test]
+    fn input_block_round_trips() {
+        let block: Block = Input::new("Name", PlainTextInput::new("name").multiline(false))
+            .block_id("name_block")
+            .into();
+        let json = serde_json::to_string(&block).unwrap();
+        assert_eq!(block, serde_json::from_str(&json).unwrap());
+    }
+}
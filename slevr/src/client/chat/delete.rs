@@ -0,0 +1,66 @@
+use crate::{ChannelId, Result, SlackApiClient, Ts};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug)]
+pub struct ChatDelete {
+    /// Channel containing the message to be deleted.
+    pub channel: ChannelId,
+    /// Timestamp of the message to be deleted.
+    pub ts: Ts,
+    /// Pass true to delete the message as the authed user. Bot users in this context are
+    /// considered authed users.
+    pub as_user: Option<bool>,
+}
+
+impl Default for ChatDelete {
+    fn default() -> Self {
+        ChatDelete {
+            channel: ChannelId::default(),
+            ts: Ts::default(),
+            as_user: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ChatDeleteResponse {
+    pub channel: ChannelId,
+    pub ts: Ts,
+}
+
+impl SlackApiClient {
+    pub async fn chat_delete(&self, delete: ChatDelete) -> Result<ChatDeleteResponse> {
+        self.post("chat.delete", &delete).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delete_serializes() {
+        let delete = ChatDelete {
+            channel: "C024BE91L".into(),
+            ts: "1401383885.000061".parse().unwrap(),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&delete).unwrap();
+        assert_eq!("C024BE91L", json["channel"]);
+        assert_eq!("1401383885.000061", json["ts"]);
+    }
+
+    #[test]
+    fn response_works() {
+        let response: ChatDeleteResponse = serde_json::from_str(
+            "{
+                \"ok\": true,
+                \"channel\": \"C024BE91L\",
+                \"ts\": \"1401383885.000061\"
+            }",
+        )
+        .unwrap();
+        assert_eq!("C024BE91L", response.channel.as_str());
+        assert_eq!(response.ts, "1401383885.000061");
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{ChannelId, Result, SlackApiClient};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug)]
+pub struct DeleteScheduledMessage {
+    /// The channel the scheduled_message is posting to
+    pub channel: ChannelId,
+    /// scheduled_message_id returned from call to chat.scheduleMessage
+    pub scheduled_message_id: String,
+    /// Pass true to delete the message as the authed user with chat:write:user scope.
+    /// Bot users in this context are considered authed users.
+    pub as_user: Option<bool>,
+}
+
+impl Default for DeleteScheduledMessage {
+    fn default() -> Self {
+        DeleteScheduledMessage {
+            channel: ChannelId::default(),
+            scheduled_message_id: "".to_string(),
+            as_user: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DeleteScheduledMessageResponse {}
+
+impl SlackApiClient {
+    pub async fn chat_delete_scheduled_message(
+        &self,
+        delete: DeleteScheduledMessage,
+    ) -> Result<DeleteScheduledMessageResponse> {
+        self.post("chat.deleteScheduledMessage", &delete).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delete_serializes() {
+        let delete = DeleteScheduledMessage {
+            channel: "C123456789".into(),
+            scheduled_message_id: "Q1234ABCD".to_string(),
+            as_user: Some(true),
+        };
+        let json = serde_json::to_value(&delete).unwrap();
+        assert_eq!("C123456789", json["channel"]);
+        assert_eq!("Q1234ABCD", json["scheduled_message_id"]);
+        assert_eq!(Some(true), json["as_user"].as_bool());
+    }
+
+    #[test]
+    fn response_works() {
+        let response = serde_json::from_str::<DeleteScheduledMessageResponse>("{\"ok\": true}");
+        assert!(response.is_ok());
+    }
+}
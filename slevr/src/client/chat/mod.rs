@@ -1 +1,7 @@
+pub mod delete;
+pub mod delete_scheduled_message;
+pub mod post_ephemeral;
 pub mod post_message;
+pub mod schedule_message;
+pub mod scheduled_messages_list;
+pub mod update;
@@ -0,0 +1,99 @@
+use crate::{blocks::Block, ChannelId, Result, SlackApiClient, Ts, UserId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug)]
+pub struct EphemeralMessage {
+    /// Channel, private group, or IM channel to send message to. Can be an encoded ID, or a name.
+    pub channel: ChannelId,
+    /// id of the user who will receive the ephemeral message. The user should be in the
+    /// channel specified by the channel argument.
+    pub user: UserId,
+    /// Text to display: More info - https://api.slack.com/methods/chat.postEphemeral#text_usage
+    pub text: String,
+    /// Structured Block Kit layout. text becomes the fallback used in notifications.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<Block>>,
+    /// Pass true to post the message as the authed user. Defaults to true if the chat:write:bot
+    /// scope is not included. Otherwise, defaults to false.
+    pub as_user: Option<bool>,
+    /// Emoji to use as the icon for this message. Overrides icon_url.
+    /// Must be used in conjunction with as_user set to false, otherwise ignored.
+    pub icon_emoji: Option<String>,
+    /// URL to an image to use as the icon for this message. Must be used in conjunction
+    /// with as_user set to false, otherwise ignored.
+    pub icon_url: Option<String>,
+    /// Find and link channel names and usernames.
+    pub link_names: Option<bool>,
+    /// Provide another message's ts value to post this message in a thread. Avoid using a
+    /// reply's ts value; use its parent's value instead. Ephemeral messages in threads are
+    /// only shown if there is already an active thread.
+    pub thread_ts: Option<Ts>,
+    /// Set your bot's user name. Must be used in conjunction with as_user set to false,
+    /// otherwise ignored.
+    pub username: Option<String>,
+}
+
+impl Default for EphemeralMessage {
+    fn default() -> Self {
+        EphemeralMessage {
+            channel: ChannelId::default(),
+            user: UserId::default(),
+            text: "".to_string(),
+            blocks: None,
+            as_user: None,
+            icon_emoji: None,
+            icon_url: None,
+            link_names: None,
+            thread_ts: None,
+            username: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct EphemeralMessageResponse {
+    pub message_ts: Ts,
+}
+
+impl SlackApiClient {
+    pub async fn chat_post_ephemeral(
+        &self,
+        message: EphemeralMessage,
+    ) -> Result<EphemeralMessageResponse> {
+        self.post("chat.postEphemeral", &message).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ephemeral_serializes() {
+        let message = EphemeralMessage {
+            channel: "C024BE91L".into(),
+            user: "U0BPQUNTA".into(),
+            text: "Only you can see this".to_string(),
+            thread_ts: Some("1401383885.000061".parse().unwrap()),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&message).unwrap();
+        assert_eq!("C024BE91L", json["channel"]);
+        assert_eq!("U0BPQUNTA", json["user"]);
+        assert_eq!("Only you can see this", json["text"]);
+        assert_eq!("1401383885.000061", json["thread_ts"]);
+        assert!(json.get("blocks").is_none());
+    }
+
+    #[test]
+    fn response_works() {
+        let response: EphemeralMessageResponse = serde_json::from_str(
+            "{
+                \"ok\": true,
+                \"message_ts\": \"1502210682.580145\"
+            }",
+        )
+        .unwrap();
+        assert_eq!(response.message_ts, "1502210682.580145");
+    }
+}
@@ -1,17 +1,16 @@
-use crate::SlackApiClient;
-use hyper::{Body, Method, Request};
-use log::debug;
+use crate::{blocks::Block, BotId, ChannelId, Result, SlackApiClient, Ts};
 use serde::{Deserialize, Serialize};
-use serde_json::{to_string, Result};
 
 #[derive(Serialize, Debug)]
 pub struct ChatMessage {
     /// Channel, private group, or IM channel to send message to. Can be an encoded ID, or a name.
-    pub channel: String,
+    pub channel: ChannelId,
     /// Text to display: More info - https://api.slack.com/methods/chat.postMessage#text_usage
     pub text: String,
     // pub attachments: Option<Vec<HashMap<String, String>>>,
-    // pub blocks: ???
+    /// Structured Block Kit layout. text becomes the fallback used in notifications.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<Block>>,
     /// Emoji to use as the icon for this message. Overrides icon_url.
     /// Must be used in conjunction with as_user set to false, otherwise ignored.
     pub icon_emoji: Option<String>,
@@ -28,7 +27,7 @@ pub struct ChatMessage {
     pub reply_broadcast: Option<bool>,
     /// Provide another message's ts value to make this message a reply. Avoid using a reply's
     /// ts value; use its parent instead.
-    pub thread_ts: Option<String>,
+    pub thread_ts: Option<Ts>,
     /// Pass true to enable unfurling of primarily text-based content.
     pub unfurl_links: Option<bool>,
     /// Pass false to disable unfurling of media content.
@@ -38,8 +37,9 @@ pub struct ChatMessage {
 impl Default for ChatMessage {
     fn default() -> Self {
         ChatMessage {
-            channel: "".to_string(),
+            channel: ChannelId::default(),
             text: "".to_string(),
+            blocks: None,
             icon_emoji: None,
             icon_url: None,
             link_names: None,
@@ -53,43 +53,24 @@ impl Default for ChatMessage {
 }
 
 #[derive(Deserialize, Debug)]
-#[serde(untagged)]
-pub enum ChatMessageResponse {
-    Error {
-        error: String,
-    },
-    Ok {
-        channel: String,
-        ts: String,
-        message: MessageResponse,
-    },
+pub struct ChatMessageResponse {
+    pub channel: ChannelId,
+    pub ts: Ts,
+    pub message: MessageResponse,
 }
+
 #[derive(Deserialize, Debug)]
 pub struct MessageResponse {
-    text: String,
-    username: String,
-    bot_id: String,
-    r#type: String,
-    subtype: String,
-    ts: String,
+    pub text: String,
+    pub username: Option<String>,
+    pub bot_id: BotId,
+    pub r#type: String,
+    pub subtype: Option<String>,
+    pub ts: Ts,
 }
 
 impl SlackApiClient {
     pub async fn chat_post_message(&self, message: ChatMessage) -> Result<ChatMessageResponse> {
-        let request = Request::builder()
-            .method(Method::POST)
-            .uri("https://slack.com/api/chat.postMessage")
-            .header("content-type", "application/json")
-            .header("Authorization", format!("Bearer {}", self.oauth))
-            .body(Body::from(to_string(&message).unwrap()))
-            .unwrap();
-        debug!("request - {:#?}", request);
-        let resp = self.client.request(request).await.unwrap();
-        debug!("response - {:#?}", resp);
-        // let (parts, body): (_, Body) = resp.into_parts();
-        let body = hyper::body::to_bytes(resp.into_body()).await.unwrap();
-        let c_m_r: Result<ChatMessageResponse> = serde_json::from_slice(&body);
-        debug!("response - {:#?}", c_m_r);
-        c_m_r
+        self.post("chat.postMessage", &message).await
     }
 }
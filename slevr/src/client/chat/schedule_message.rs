@@ -0,0 +1,117 @@
+use crate::{blocks::Block, ChannelId, Result, SlackApiClient, Ts};
+use serde::{Deserialize, Serialize};
+use std::time::SystemTime;
+
+#[derive(Serialize, Debug)]
+pub struct ScheduledMessage {
+    /// Channel, private group, or DM channel to send message to. Can be an encoded ID, or a name.
+    pub channel: ChannelId,
+    /// When the message should be posted. Slack only accepts times up to 120 days out, and
+    /// a time before the unix epoch fails the call with `Error::Json`.
+    #[serde(with = "crate::client::unix_time")]
+    pub post_at: SystemTime,
+    /// Text to display: More info - https://api.slack.com/methods/chat.scheduleMessage#text_usage
+    pub text: String,
+    /// Structured Block Kit layout. text becomes the fallback used in notifications.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<Block>>,
+    /// Pass true to post the message as the authed user, instead of as a bot.
+    pub as_user: Option<bool>,
+    /// Find and link channel names and usernames.
+    pub link_names: Option<bool>,
+    /// Used in conjunction with thread_ts and indicates whether reply should be made visible
+    /// to everyone in the channel or conversation. Defaults to false.
+    pub reply_broadcast: Option<bool>,
+    /// Provide another message's ts value to make this message a reply. Avoid using a reply's
+    /// ts value; use its parent instead.
+    pub thread_ts: Option<Ts>,
+    /// Pass true to enable unfurling of primarily text-based content.
+    pub unfurl_links: Option<bool>,
+    /// Pass false to disable unfurling of media content.
+    pub unfurl_media: Option<bool>,
+}
+
+impl ScheduledMessage {
+    /// There's no sensible default for when to post, so every message starts with one.
+    pub fn new(
+        channel: impl Into<ChannelId>,
+        post_at: SystemTime,
+        text: impl Into<String>,
+    ) -> Self {
+        ScheduledMessage {
+            channel: channel.into(),
+            post_at,
+            text: text.into(),
+            blocks: None,
+            as_user: None,
+            link_names: None,
+            reply_broadcast: None,
+            thread_ts: None,
+            unfurl_links: None,
+            unfurl_media: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ScheduledMessageResponse {
+    pub channel: ChannelId,
+    pub scheduled_message_id: String,
+    #[serde(with = "crate::client::unix_time")]
+    pub post_at: SystemTime,
+}
+
+impl SlackApiClient {
+    pub async fn chat_schedule_message(
+        &self,
+        message: ScheduledMessage,
+    ) -> Result<ScheduledMessageResponse> {
+        self.post("chat.scheduleMessage", &message).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn post_at_is_unix_seconds() {
+        let message = ScheduledMessage::new(
+            "C1234567890",
+            UNIX_EPOCH + Duration::from_secs(1_551_991_428),
+            "Here's a message for you in the future",
+        );
+        let json = serde_json::to_value(&message).unwrap();
+        assert_eq!(1_551_991_428, json["post_at"]);
+    }
+
+    #[test]
+    fn post_at_before_epoch_is_an_error() {
+        let message = ScheduledMessage::new(
+            "C1234567890",
+            UNIX_EPOCH - Duration::from_secs(1),
+            "Here's a message for you in the past",
+        );
+        let err = serde_json::to_string(&message).unwrap_err();
+        assert!(err.to_string().contains("before the unix epoch"));
+    }
+
+    #[test]
+    fn response_works() {
+        let response: ScheduledMessageResponse = serde_json::from_str(
+            "{
+                \"ok\": true,
+                \"channel\": \"C1H9RESGL\",
+                \"scheduled_message_id\": \"Q1298393284\",
+                \"post_at\": 1562180400
+            }",
+        )
+        .unwrap();
+        assert_eq!("Q1298393284", response.scheduled_message_id);
+        assert_eq!(
+            UNIX_EPOCH + Duration::from_secs(1_562_180_400),
+            response.post_at
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{ChannelId, ResponseMetadata, Result, SlackApiClient};
+use serde::{Deserialize, Serialize};
+use std::time::SystemTime;
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ScheduledMessagesList {
+    /// The channel of the scheduled messages
+    pub channel: Option<ChannelId>,
+    /// For pagination purposes, this is the cursor value returned from a previous call
+    /// to chat.scheduledmessages.list indicating where you want to start this call from.
+    pub cursor: Option<String>,
+    /// Only messages scheduled before this time will be returned.
+    #[serde(with = "crate::client::unix_time::option")]
+    pub latest: Option<SystemTime>,
+    /// Maximum number of original entries to return.
+    pub limit: Option<u32>,
+    /// Only messages scheduled after this time will be returned.
+    #[serde(with = "crate::client::unix_time::option")]
+    pub oldest: Option<SystemTime>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ScheduledMessagesListResponse {
+    pub scheduled_messages: Vec<ScheduledMessageSummary>,
+    pub response_metadata: Option<ResponseMetadata>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ScheduledMessageSummary {
+    pub id: String,
+    pub channel_id: ChannelId,
+    #[serde(with = "crate::client::unix_time")]
+    pub post_at: SystemTime,
+    #[serde(with = "crate::client::unix_time")]
+    pub date_created: SystemTime,
+    pub text: Option<String>,
+}
+
+impl SlackApiClient {
+    pub async fn chat_scheduled_messages_list(
+        &self,
+        list: ScheduledMessagesList,
+    ) -> Result<ScheduledMessagesListResponse> {
+        self.post("chat.scheduledMessages.list", &list).await
+    }
+}
+
+paginated!(
+    ScheduledMessagesList => chat_scheduled_messages_list,
+    scheduled_messages: ScheduledMessageSummary
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn oldest_is_unix_seconds() {
+        let list = ScheduledMessagesList {
+            channel: Some("C1H9RESGL".into()),
+            oldest: Some(UNIX_EPOCH + Duration::from_millis(1_562_137_200_500)),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&list).unwrap();
+        assert_eq!("C1H9RESGL", json["channel"]);
+        assert_eq!(1_562_137_200, json["oldest"]);
+        assert!(json["latest"].is_null());
+    }
+
+    #[test]
+    fn response_works() {
+        let response: ScheduledMessagesListResponse = serde_json::from_str(
+            "{
+                \"ok\": true,
+                \"scheduled_messages\": [
+                    {
+                        \"id\": \"1298393284\",
+                        \"channel_id\": \"C1H9RESGL\",
+                        \"post_at\": 1551991428,
+                        \"date_created\": 1551891734,
+                        \"text\": \"Here's a message for you in the future\"
+                    }
+                ],
+                \"response_metadata\": {
+                    \"next_cursor\": \"\"
+                }
+            }",
+        )
+        .unwrap();
+        let message = &response.scheduled_messages[0];
+        assert_eq!("1298393284", message.id);
+        assert_eq!("C1H9RESGL", message.channel_id.as_str());
+        assert_eq!(
+            UNIX_EPOCH + Duration::from_secs(1_551_991_428),
+            message.post_at
+        );
+        assert_eq!(
+            UNIX_EPOCH + Duration::from_secs(1_551_891_734),
+            message.date_created
+        );
+    }
+}
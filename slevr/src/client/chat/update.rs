@@ -0,0 +1,95 @@
+use crate::{blocks::Block, ChannelId, Result, SlackApiClient, Ts};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug)]
+pub struct ChatUpdate {
+    /// Channel containing the message to be updated.
+    pub channel: ChannelId,
+    /// Timestamp of the message to be updated.
+    pub ts: Ts,
+    /// New text for the message. More info - https://api.slack.com/methods/chat.update#text_usage
+    pub text: String,
+    /// Structured Block Kit layout. text becomes the fallback used in notifications.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<Block>>,
+    /// Pass true to update the message as the authed user. Bot users in this context are
+    /// considered authed users.
+    pub as_user: Option<bool>,
+    /// Find and link channel names and usernames. Defaults to none.
+    pub link_names: Option<bool>,
+    /// Broadcast an existing thread reply to make it visible to everyone in the channel
+    /// or conversation.
+    pub reply_broadcast: Option<bool>,
+}
+
+impl Default for ChatUpdate {
+    fn default() -> Self {
+        ChatUpdate {
+            channel: ChannelId::default(),
+            ts: Ts::default(),
+            text: "".to_string(),
+            blocks: None,
+            as_user: None,
+            link_names: None,
+            reply_broadcast: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ChatUpdateResponse {
+    pub channel: ChannelId,
+    pub ts: Ts,
+    pub text: String,
+}
+
+impl SlackApiClient {
+    pub async fn chat_update(&self, update: ChatUpdate) -> Result<ChatUpdateResponse> {
+        self.post("chat.update", &update).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blocks::BlocksBuilder;
+
+    #[test]
+    fn update_serializes() {
+        let update = ChatUpdate {
+            channel: "C024BE91L".into(),
+            ts: "1401383885.000061".parse().unwrap(),
+            text: "Updated text you carefully authored".to_string(),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&update).unwrap();
+        assert_eq!("C024BE91L", json["channel"]);
+        assert_eq!("1401383885.000061", json["ts"]);
+        assert_eq!("Updated text you carefully authored", json["text"]);
+        assert!(json.get("blocks").is_none());
+
+        let update = ChatUpdate {
+            blocks: Some(BlocksBuilder::new().text("Updated").build()),
+            ..update
+        };
+        let json = serde_json::to_value(&update).unwrap();
+        assert_eq!("section", json["blocks"][0]["type"]);
+    }
+
+    #[test]
+    fn response_works() {
+        let response: ChatUpdateResponse = serde_json::from_str(
+            "{
+                \"ok\": true,
+                \"channel\": \"C024BE91L\",
+                \"ts\": \"1401383885.000061\",
+                \"text\": \"Updated text you carefully authored\",
+                \"user\": \"U34567890\"
+            }",
+        )
+        .unwrap();
+        assert_eq!("C024BE91L", response.channel.as_str());
+        assert_eq!(response.ts, "1401383885.000061");
+        assert_eq!("Updated text you carefully authored", response.text);
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{ChannelId, Result, SlackApiClient};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Default)]
+pub struct ConversationsArchive {
+    pub channel: ChannelId,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ConversationsArchiveResponse {}
+
+impl SlackApiClient {
+    pub async fn conversations_archive(
+        &self,
+        archive: ConversationsArchive,
+    ) -> Result<ConversationsArchiveResponse> {
+        self.post("conversations.archive", &archive).await
+    }
+}
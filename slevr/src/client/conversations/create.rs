@@ -0,0 +1,45 @@
+use super::Conversation;
+use crate::{Result, SlackApiClient, TeamId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Default)]
+pub struct ConversationsCreate {
+    /// Lowercase letters, numbers, hyphens and underscores, up to 80 characters.
+    pub name: String,
+    /// Create a private channel instead of a public one
+    pub is_private: Option<bool>,
+    /// Required for org-wide apps
+    pub team_id: Option<TeamId>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ConversationsCreateResponse {
+    pub channel: Conversation,
+}
+
+impl SlackApiClient {
+    pub async fn conversations_create(
+        &self,
+        create: ConversationsCreate,
+    ) -> Result<ConversationsCreateResponse> {
+        self.post("conversations.create", &create).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_serializes() {
+        let create = ConversationsCreate {
+            name: "endeavor".to_string(),
+            is_private: Some(true),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&create).unwrap();
+        assert_eq!("endeavor", json["name"]);
+        assert_eq!(Some(true), json["is_private"].as_bool());
+        assert!(json["team_id"].is_null());
+    }
+}
@@ -0,0 +1,40 @@
+use super::HistoryMessage;
+use crate::{ChannelId, ResponseMetadata, Result, SlackApiClient, Ts};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ConversationsHistory {
+    pub channel: ChannelId,
+    /// Paginate through collections of data by setting the cursor parameter to a
+    /// next_cursor attribute returned by a previous request's response_metadata.
+    pub cursor: Option<String>,
+    /// Include messages with latest or oldest timestamp in results only when either
+    /// timestamp is specified.
+    pub inclusive: Option<bool>,
+    /// End of time range of messages to include in results. Defaults to now.
+    pub latest: Option<Ts>,
+    /// The maximum number of items to return, no more than 1000. Defaults to 100.
+    pub limit: Option<u32>,
+    /// Start of time range of messages to include in results.
+    pub oldest: Option<Ts>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ConversationsHistoryResponse {
+    /// Newest first
+    pub messages: Vec<HistoryMessage>,
+    pub has_more: bool,
+    pub response_metadata: Option<ResponseMetadata>,
+}
+
+impl SlackApiClient {
+    /// Fetches a conversation's messages, not including thread replies.
+    pub async fn conversations_history(
+        &self,
+        history: ConversationsHistory,
+    ) -> Result<ConversationsHistoryResponse> {
+        self.post_form("conversations.history", &history).await
+    }
+}
+
+paginated!(ConversationsHistory => conversations_history, messages: HistoryMessage);
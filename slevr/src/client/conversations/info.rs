@@ -0,0 +1,26 @@
+use super::Conversation;
+use crate::{ChannelId, Result, SlackApiClient};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Default)]
+pub struct ConversationsInfo {
+    pub channel: ChannelId,
+    /// Set this to true to receive the locale for this conversation. Defaults to false
+    pub include_locale: Option<bool>,
+    /// Set to true to include the member count for the specified conversation.
+    pub include_num_members: Option<bool>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ConversationsInfoResponse {
+    pub channel: Conversation,
+}
+
+impl SlackApiClient {
+    pub async fn conversations_info(
+        &self,
+        info: ConversationsInfo,
+    ) -> Result<ConversationsInfoResponse> {
+        self.post_form("conversations.info", &info).await
+    }
+}
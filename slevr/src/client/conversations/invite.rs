@@ -0,0 +1,43 @@
+use super::Conversation;
+use crate::{ChannelId, Result, SlackApiClient, UserId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Default)]
+pub struct ConversationsInvite {
+    pub channel: ChannelId,
+    /// Up to 1000 users
+    pub users: Vec<UserId>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ConversationsInviteResponse {
+    pub channel: Conversation,
+}
+
+impl SlackApiClient {
+    pub async fn conversations_invite(
+        &self,
+        invite: ConversationsInvite,
+    ) -> Result<ConversationsInviteResponse> {
+        // users is a comma separated list, which the form encoding takes care of.
+        self.post_form("conversations.invite", &invite).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::form;
+
+    #[test]
+    fn users_are_comma_separated() {
+        let invite = ConversationsInvite {
+            channel: "C012AB3CD".into(),
+            users: vec!["W1234567890".into(), "U2345678901".into()],
+        };
+        assert_eq!(
+            "channel=C012AB3CD&users=W1234567890%2CU2345678901",
+            form::to_string(&invite).unwrap()
+        );
+    }
+}
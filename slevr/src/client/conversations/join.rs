@@ -0,0 +1,39 @@
+use super::Conversation;
+use crate::{ChannelId, Result, SlackApiClient};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Default)]
+pub struct ConversationsJoin {
+    pub channel: ChannelId,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ConversationsJoinResponse {
+    pub channel: Conversation,
+    /// Set when the caller was already in the channel
+    pub warning: Option<String>,
+}
+
+impl SlackApiClient {
+    /// Joins a public channel.
+    pub async fn conversations_join(
+        &self,
+        join: ConversationsJoin,
+    ) -> Result<ConversationsJoinResponse> {
+        self.post("conversations.join", &join).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn join_serializes() {
+        let join = ConversationsJoin {
+            channel: "C061EG9SL".into(),
+        };
+        let json = serde_json::to_value(&join).unwrap();
+        assert_eq!("C061EG9SL", json["channel"]);
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{ChannelId, Result, SlackApiClient, UserId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Default)]
+pub struct ConversationsKick {
+    pub channel: ChannelId,
+    pub user: UserId,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ConversationsKickResponse {}
+
+impl SlackApiClient {
+    pub async fn conversations_kick(
+        &self,
+        kick: ConversationsKick,
+    ) -> Result<ConversationsKickResponse> {
+        self.post("conversations.kick", &kick).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kick_serializes() {
+        let kick = ConversationsKick {
+            channel: "C012AB3CD".into(),
+            user: "W123456789".into(),
+        };
+        let json = serde_json::to_value(&kick).unwrap();
+        assert_eq!("C012AB3CD", json["channel"]);
+        assert_eq!("W123456789", json["user"]);
+    }
+}
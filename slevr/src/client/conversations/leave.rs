@@ -0,0 +1,22 @@
+use crate::{ChannelId, Result, SlackApiClient};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Default)]
+pub struct ConversationsLeave {
+    pub channel: ChannelId,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ConversationsLeaveResponse {
+    /// Set when the caller wasn't in the channel to begin with
+    pub not_in_channel: Option<bool>,
+}
+
+impl SlackApiClient {
+    pub async fn conversations_leave(
+        &self,
+        leave: ConversationsLeave,
+    ) -> Result<ConversationsLeaveResponse> {
+        self.post("conversations.leave", &leave).await
+    }
+}
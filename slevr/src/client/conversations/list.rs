@@ -0,0 +1,69 @@
+use super::Conversation;
+use crate::{ResponseMetadata, Result, SlackApiClient, TeamId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversationType {
+    PublicChannel,
+    PrivateChannel,
+    Mpim,
+    Im,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ConversationsList {
+    /// Paginate through collections of data by setting the cursor parameter to a
+    /// next_cursor attribute returned by a previous request's response_metadata.
+    pub cursor: Option<String>,
+    /// Set to true to exclude archived channels from the list
+    pub exclude_archived: Option<bool>,
+    /// The maximum number of items to return, no more than 1000. Defaults to 100.
+    pub limit: Option<u32>,
+    /// Which kinds of conversation to list, only public channels by default.
+    pub types: Option<Vec<ConversationType>>,
+    /// Required for org-wide apps
+    pub team_id: Option<TeamId>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ConversationsListResponse {
+    pub channels: Vec<Conversation>,
+    pub response_metadata: Option<ResponseMetadata>,
+}
+
+impl SlackApiClient {
+    /// Lists the conversations in a workspace.
+    pub async fn conversations_list(
+        &self,
+        list: ConversationsList,
+    ) -> Result<ConversationsListResponse> {
+        self.post_form("conversations.list", &list).await
+    }
+}
+
+paginated!(ConversationsList => conversations_list, channels: Conversation);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::form;
+
+    #[test]
+    fn types_are_comma_separated() {
+        let list = ConversationsList {
+            exclude_archived: Some(true),
+            limit: Some(100),
+            types: Some(vec![
+                ConversationType::PublicChannel,
+                ConversationType::PrivateChannel,
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(
+            "exclude_archived=true&limit=100&types=public_channel%2Cprivate_channel",
+            form::to_string(&list).unwrap()
+        );
+        assert_eq!("", form::to_string(&ConversationsList::default()).unwrap());
+    }
+}
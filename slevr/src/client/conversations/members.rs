@@ -0,0 +1,29 @@
+use crate::{ChannelId, ResponseMetadata, Result, SlackApiClient, UserId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ConversationsMembers {
+    pub channel: ChannelId,
+    /// Paginate through collections of data by setting the cursor parameter to a
+    /// next_cursor attribute returned by a previous request's response_metadata.
+    pub cursor: Option<String>,
+    /// The maximum number of items to return, no more than 1000. Defaults to 100.
+    pub limit: Option<u32>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ConversationsMembersResponse {
+    pub members: Vec<UserId>,
+    pub response_metadata: Option<ResponseMetadata>,
+}
+
+impl SlackApiClient {
+    pub async fn conversations_members(
+        &self,
+        members: ConversationsMembers,
+    ) -> Result<ConversationsMembersResponse> {
+        self.post_form("conversations.members", &members).await
+    }
+}
+
+paginated!(ConversationsMembers => conversations_members, members: UserId);
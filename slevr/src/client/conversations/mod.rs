@@ -0,0 +1,184 @@
+//! Channels, private channels, DMs and group DMs, which Slack calls conversations.
+//! More info - https://api.slack.com/docs/conversations-api
+use crate::events::MessageSubtype;
+use crate::reactions::Reaction;
+use crate::{ChannelId, TeamId, Ts, UserId};
+use serde::Deserialize;
+
+pub mod archive;
+pub mod create;
+pub mod history;
+pub mod info;
+pub mod invite;
+pub mod join;
+pub mod kick;
+pub mod leave;
+pub mod list;
+pub mod members;
+pub mod open;
+pub mod replies;
+pub mod set_purpose;
+pub mod set_topic;
+
+/// A conversation, as conversations.info and friends describe it. Which fields are set
+/// depends on the kind of conversation and the method.
+/// More info - https://api.slack.com/types/conversation
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Conversation {
+    pub id: ChannelId,
+    /// DMs don't have names
+    pub name: Option<String>,
+    pub name_normalized: Option<String>,
+    #[serde(default)]
+    pub previous_names: Vec<String>,
+    #[serde(default)]
+    pub is_channel: bool,
+    #[serde(default)]
+    pub is_group: bool,
+    #[serde(default)]
+    pub is_im: bool,
+    #[serde(default)]
+    pub is_mpim: bool,
+    #[serde(default)]
+    pub is_private: bool,
+    #[serde(default)]
+    pub is_archived: bool,
+    /// The workspace's #general
+    #[serde(default)]
+    pub is_general: bool,
+    #[serde(default)]
+    pub is_shared: bool,
+    #[serde(default)]
+    pub is_ext_shared: bool,
+    #[serde(default)]
+    pub is_org_shared: bool,
+    /// Whether the calling user is in the conversation
+    pub is_member: Option<bool>,
+    pub created: Option<u64>,
+    pub creator: Option<UserId>,
+    /// The other person in a DM
+    pub user: Option<UserId>,
+    pub context_team_id: Option<TeamId>,
+    pub topic: Option<Topic>,
+    pub purpose: Option<Topic>,
+    /// Only when asked for with include_num_members
+    pub num_members: Option<u32>,
+    /// Where the calling user has read up to
+    pub last_read: Option<Ts>,
+    pub unread_count: Option<u32>,
+    pub unread_count_display: Option<u32>,
+    /// Only when asked for with include_locale
+    pub locale: Option<String>,
+}
+
+/// A conversation's topic or purpose.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Topic {
+    pub value: String,
+    /// Empty when it's never been set
+    pub creator: UserId,
+    pub last_set: u64,
+}
+
+/// A message from conversations.history or conversations.replies.
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct HistoryMessage {
+    pub ts: Ts,
+    /// Set on thread parents and replies
+    pub thread_ts: Option<Ts>,
+    /// Set on replies, who started the thread
+    pub parent_user_id: Option<UserId>,
+    /// Set on thread parents
+    pub reply_count: Option<u32>,
+    pub latest_reply: Option<Ts>,
+    #[serde(default)]
+    pub reactions: Vec<Reaction>,
+    #[serde(flatten)]
+    pub subtype: MessageSubtype,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::UserMessage;
+
+    #[test]
+    fn conversation_works() {
+        let conversation: Conversation = serde_json::from_str(
+            r#"{
+                "id": "C012AB3CD",
+                "name": "general",
+                "is_channel": true,
+                "is_group": false,
+                "is_im": false,
+                "created": 1449252889,
+                "creator": "W012A3BCD",
+                "is_archived": false,
+                "is_general": true,
+                "unlinked": 0,
+                "name_normalized": "general",
+                "is_read_only": false,
+                "is_shared": false,
+                "parent_conversation": null,
+                "is_ext_shared": false,
+                "is_org_shared": false,
+                "pending_shared": [],
+                "is_pending_ext_shared": false,
+                "is_member": true,
+                "is_private": false,
+                "is_mpim": false,
+                "last_read": "1502126650.228446",
+                "topic": {
+                    "value": "For public discussion of generalities",
+                    "creator": "W012A3BCD",
+                    "last_set": 1449709364
+                },
+                "purpose": {
+                    "value": "This part of the workspace is for fun. Make fun here.",
+                    "creator": "W012A3BCD",
+                    "last_set": 1449709364
+                },
+                "previous_names": ["specifics", "abstractions", "etc"],
+                "locale": "en-US"
+            }"#,
+        )
+        .unwrap();
+        assert!(conversation.is_general);
+        assert_eq!(Some("general"), conversation.name.as_deref());
+        assert_eq!(Some(true), conversation.is_member);
+        assert_eq!(
+            "For public discussion of generalities",
+            conversation.topic.unwrap().value
+        );
+        assert_eq!(3, conversation.previous_names.len());
+
+        let im: Conversation = serde_json::from_str(
+            r#"{"id": "D069C7QFK", "created": 1435454909, "is_im": true, "is_org_shared": false, "user": "U069C7QF3", "is_user_deleted": false, "priority": 0}"#,
+        )
+        .unwrap();
+        assert!(im.is_im);
+        assert_eq!(None, im.name);
+        assert_eq!(Some(UserId::from("U069C7QF3")), im.user);
+    }
+
+    #[test]
+    fn history_message_works() {
+        let message: HistoryMessage = serde_json::from_str(
+            r#"{
+                "type": "message",
+                "user": "U012AB3CDE",
+                "text": "I find you punny and would like to smell your nose letter",
+                "ts": "1512085950.000216",
+                "thread_ts": "1512085950.000216",
+                "reply_count": 2,
+                "latest_reply": "1512104434.000490"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(Some(2), message.reply_count);
+        match message.subtype {
+            MessageSubtype::Plain(UserMessage { user, .. }) => assert_eq!("U012AB3CDE", user),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use super::Conversation;
+use crate::{ChannelId, Result, SlackApiClient, UserId};
+use serde::{Deserialize, Serialize};
+
+/// Pass either the channel of an existing DM or group DM, or the users to have one with.
+#[derive(Serialize, Debug, Default)]
+pub struct ConversationsOpen {
+    /// Resume a conversation by supplying an im or mpim's ID.
+    pub channel: Option<ChannelId>,
+    /// One user for a DM, or up to 8 for a group DM. The calling user is implied.
+    pub users: Option<Vec<UserId>>,
+    /// Boolean, indicates you want the full IM channel definition in the response.
+    pub return_im: Option<bool>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ConversationsOpenResponse {
+    /// Only the id unless return_im was set
+    pub channel: Conversation,
+    pub no_op: Option<bool>,
+    pub already_open: Option<bool>,
+}
+
+impl SlackApiClient {
+    /// Opens or resumes a DM or group DM, e.g. to find the channel to message a user in.
+    pub async fn conversations_open(
+        &self,
+        open: ConversationsOpen,
+    ) -> Result<ConversationsOpenResponse> {
+        // users is a comma separated list, which the form encoding takes care of.
+        self.post_form("conversations.open", &open).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::form;
+
+    #[test]
+    fn users_are_comma_separated() {
+        let open = ConversationsOpen {
+            users: Some(vec!["W1234567890".into(), "U2345678901".into()]),
+            return_im: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(
+            "return_im=true&users=W1234567890%2CU2345678901",
+            form::to_string(&open).unwrap()
+        );
+
+        let open = ConversationsOpen {
+            channel: Some("D069C7QFK".into()),
+            ..Default::default()
+        };
+        assert_eq!("channel=D069C7QFK", form::to_string(&open).unwrap());
+    }
+}
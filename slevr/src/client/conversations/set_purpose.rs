@@ -0,0 +1,23 @@
+use super::Conversation;
+use crate::{ChannelId, Result, SlackApiClient};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Default)]
+pub struct ConversationsSetPurpose {
+    pub channel: ChannelId,
+    pub purpose: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ConversationsSetPurposeResponse {
+    pub channel: Conversation,
+}
+
+impl SlackApiClient {
+    pub async fn conversations_set_purpose(
+        &self,
+        set_purpose: ConversationsSetPurpose,
+    ) -> Result<ConversationsSetPurposeResponse> {
+        self.post("conversations.setPurpose", &set_purpose).await
+    }
+}
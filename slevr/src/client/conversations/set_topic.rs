@@ -0,0 +1,40 @@
+use super::Conversation;
+use crate::{ChannelId, Result, SlackApiClient};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Default)]
+pub struct ConversationsSetTopic {
+    pub channel: ChannelId,
+    /// Doesn't support formatting or linkification.
+    pub topic: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ConversationsSetTopicResponse {
+    pub channel: Conversation,
+}
+
+impl SlackApiClient {
+    pub async fn conversations_set_topic(
+        &self,
+        set_topic: ConversationsSetTopic,
+    ) -> Result<ConversationsSetTopicResponse> {
+        self.post("conversations.setTopic", &set_topic).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_topic_serializes() {
+        let set_topic = ConversationsSetTopic {
+            channel: "C012AB3CD".into(),
+            topic: "Apply topically for best effects".to_string(),
+        };
+        let json = serde_json::to_value(&set_topic).unwrap();
+        assert_eq!("C012AB3CD", json["channel"]);
+        assert_eq!("Apply topically for best effects", json["topic"]);
+    }
+}
@@ -0,0 +1,105 @@
+use super::get_upload_url_external::FilesGetUploadUrlExternal;
+use crate::events::File;
+use crate::{ChannelId, Result, SlackApiClient, Ts};
+use serde::{Deserialize, Serialize, Serializer};
+use tokio::io::AsyncRead;
+
+/// An uploaded file to complete.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct FileSummary {
+    pub id: String,
+    /// Defaults to the filename
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct FilesCompleteUploadExternal {
+    pub files: Vec<FileSummary>,
+    /// Share the files to this channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<ChannelId>,
+    /// Share the files to several channels
+    #[serde(
+        serialize_with = "comma_separated",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub channels: Option<Vec<ChannelId>>,
+    /// The message to share the files with
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_comment: Option<String>,
+    /// Share them as a reply in this thread, which needs channel_id too
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<Ts>,
+}
+
+/// Slack wants a string like `C1,C2`, not a list.
+fn comma_separated<S: Serializer>(
+    channels: &Option<Vec<ChannelId>>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    let channels = channels.iter().flatten().map(ChannelId::to_string);
+    serializer.serialize_str(&channels.collect::<Vec<_>>().join(","))
+}
+
+#[derive(Deserialize, Debug)]
+pub struct FilesCompleteUploadExternalResponse {
+    /// Only the id and title are filled in
+    pub files: Vec<File>,
+}
+
+impl SlackApiClient {
+    /// The last step of an upload, see `upload_file`.
+    pub async fn files_complete_upload_external(
+        &self,
+        complete: FilesCompleteUploadExternal,
+    ) -> Result<FilesCompleteUploadExternalResponse> {
+        self.post("files.completeUploadExternal", &complete).await
+    }
+
+    /// Uploads `get.length` bytes of `file` the way Slack recommends: asks for an upload URL,
+    /// streams the file to it, then completes the upload and shares it as `complete` says.
+    /// The new file is added to `complete.files` if it's left empty.
+    pub async fn upload_file<R>(
+        &self,
+        get: FilesGetUploadUrlExternal,
+        file: R,
+        mut complete: FilesCompleteUploadExternal,
+    ) -> Result<FilesCompleteUploadExternalResponse>
+    where
+        R: AsyncRead + Send + Unpin + 'static,
+    {
+        let length = get.length;
+        let upload = self.files_get_upload_url_external(get).await?;
+        self.send_upload(&upload.upload_url, length, file).await?;
+        if complete.files.is_empty() {
+            complete.files.push(FileSummary {
+                id: upload.file_id,
+                title: None,
+            });
+        }
+        self.files_complete_upload_external(complete).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn complete_upload_external_works() {
+        assert_eq!(
+            r#"{"files":[{"id":"F044GKUHN9Z"}],"channels":"C1,C2","initial_comment":"Big event"}"#,
+            serde_json::to_string(&FilesCompleteUploadExternal {
+                files: vec![FileSummary {
+                    id: "F044GKUHN9Z".to_string(),
+                    title: None,
+                }],
+                channels: Some(vec!["C1".into(), "C2".into()]),
+                initial_comment: Some("Big event".to_string()),
+                ..Default::default()
+            })
+            .unwrap()
+        );
+    }
+}
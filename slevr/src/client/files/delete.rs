@@ -0,0 +1,16 @@
+use crate::{Result, SlackApiClient};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Default)]
+pub struct FilesDelete {
+    pub file: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct FilesDeleteResponse {}
+
+impl SlackApiClient {
+    pub async fn files_delete(&self, delete: FilesDelete) -> Result<FilesDeleteResponse> {
+        self.post("files.delete", &delete).await
+    }
+}
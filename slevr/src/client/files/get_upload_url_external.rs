@@ -0,0 +1,30 @@
+use crate::{Result, SlackApiClient};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Default)]
+pub struct FilesGetUploadUrlExternal {
+    pub filename: String,
+    /// In bytes
+    pub length: u64,
+    /// A description of an image for screen readers
+    pub alt_txt: Option<String>,
+    /// Makes the file a snippet of this type, e.g. `text` or `json`
+    pub snippet_type: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct FilesGetUploadUrlExternalResponse {
+    /// Where to POST the file's bytes
+    pub upload_url: String,
+    pub file_id: String,
+}
+
+impl SlackApiClient {
+    /// The first step of an upload, see `upload_file`.
+    pub async fn files_get_upload_url_external(
+        &self,
+        get: FilesGetUploadUrlExternal,
+    ) -> Result<FilesGetUploadUrlExternalResponse> {
+        self.post_form("files.getUploadURLExternal", &get).await
+    }
+}
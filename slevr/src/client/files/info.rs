@@ -0,0 +1,28 @@
+use crate::events::File;
+use crate::{ResponseMetadata, Result, SlackApiClient};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Serialize, Debug, Default)]
+pub struct FilesInfo {
+    pub file: String,
+    /// Paginate through the comments by setting the cursor parameter to a next_cursor
+    /// attribute returned by a previous request's response_metadata.
+    pub cursor: Option<String>,
+    /// The maximum number of comments to return.
+    pub limit: Option<u32>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct FilesInfoResponse {
+    pub file: File,
+    #[serde(default)]
+    pub comments: Vec<Value>,
+    pub response_metadata: Option<ResponseMetadata>,
+}
+
+impl SlackApiClient {
+    pub async fn files_info(&self, info: FilesInfo) -> Result<FilesInfoResponse> {
+        self.post_form("files.info", &info).await
+    }
+}
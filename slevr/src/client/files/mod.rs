@@ -0,0 +1,205 @@
+//! Uploading, looking up and downloading files. The file model itself is `events::File`,
+//! since file_share messages carry it too.
+//! More info - https://api.slack.com/messaging/files
+use super::retry;
+use crate::error::{Error, Result};
+use crate::SlackApiClient;
+use futures::future::poll_fn;
+use futures::stream::{self, Stream, StreamExt};
+use hyper::body::Bytes;
+use hyper::{Body, Method, Request, StatusCode, Uri};
+use log::debug;
+use std::io;
+use std::pin::Pin;
+use tokio::io::AsyncRead;
+
+pub mod complete_upload_external;
+pub mod delete;
+pub mod get_upload_url_external;
+pub mod info;
+pub mod upload;
+
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Reads `reader` to the end a chunk at a time, so a file never has to fit in memory.
+fn read_chunks<R>(reader: R) -> impl Stream<Item = io::Result<Bytes>> + Send + 'static
+where
+    R: AsyncRead + Send + Unpin + 'static,
+{
+    stream::unfold(Some(reader), |reader| async move {
+        let mut reader = reader?;
+        let mut chunk = vec![0; CHUNK_SIZE];
+        match poll_fn(|cx| Pin::new(&mut reader).poll_read(cx, &mut chunk)).await {
+            Ok(0) => None,
+            Ok(read) => {
+                chunk.truncate(read);
+                Some((Ok(Bytes::from(chunk)), Some(reader)))
+            }
+            // Stop reading after an error, the upload has failed either way.
+            Err(err) => Some((Err(err), None)),
+        }
+    })
+}
+
+/// Quotes and line breaks would end the header early.
+fn header_value(value: &str) -> String {
+    value.replace(&['\r', '\n'][..], "").replace('"', "%22")
+}
+
+/// A `multipart/form-data` body with `fields` and then `file`, returning the content type to
+/// send it with, which names the boundary.
+fn multipart<R>(
+    fields: Vec<(String, String)>,
+    filename: &str,
+    file: R,
+) -> (
+    String,
+    impl Stream<Item = io::Result<Bytes>> + Send + 'static,
+)
+where
+    R: AsyncRead + Send + Unpin + 'static,
+{
+    let boundary = format!("slevr-{:016x}", rand::random::<u64>());
+    let mut head = String::new();
+    for (name, value) in fields {
+        head.push_str(&format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n{}\r\n",
+            boundary,
+            header_value(&name),
+            value
+        ));
+    }
+    head.push_str(&format!(
+        "--{}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"{}\"\r\nContent-Type: application/octet-stream\r\n\r\n",
+        boundary,
+        header_value(filename)
+    ));
+    let tail = format!("\r\n--{}--\r\n", boundary);
+    let body = stream::once(async { Ok(Bytes::from(head)) })
+        .chain(read_chunks(file))
+        .chain(stream::once(async { Ok(Bytes::from(tail)) }));
+    (format!("multipart/form-data; boundary={}", boundary), body)
+}
+
+impl SlackApiClient {
+    /// Fetches a file's `url_private` or `url_private_download`, which need the bot token.
+    /// The body is a stream of the file's bytes, or use `hyper::body::to_bytes` to read it
+    /// all at once. Anything not on `https://files.slack.com` is refused, since the token
+    /// would go along with it.
+    pub async fn download(&self, url: &str) -> Result<Body> {
+        let uri: Uri = url.parse().map_err(hyper::http::Error::from)?;
+        if uri.scheme_str() != Some("https")
+            || uri.host() != Some("files.slack.com")
+            || uri.port().is_some()
+        {
+            return Err(Error::UntrustedUrl(url.to_string()));
+        }
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri(uri)
+            .header("Authorization", format!("Bearer {}", self.oauth))
+            .body(Body::empty())?;
+        let resp = self.client.request(request).await?;
+        debug!("response - {:#?}", resp);
+        let status = resp.status();
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            return Err(Error::RateLimited {
+                retry_after: retry::retry_after(resp.headers()),
+            });
+        }
+        if !status.is_success() {
+            let body = hyper::body::to_bytes(resp.into_body()).await?;
+            return Err(Error::Http {
+                status,
+                body: String::from_utf8_lossy(&body).into_owned(),
+            });
+        }
+        Ok(resp.into_body())
+    }
+
+    /// Streams `length` bytes of `file` to an upload_url from files.getUploadURLExternal.
+    async fn send_upload<R>(&self, upload_url: &str, length: u64, file: R) -> Result<()>
+    where
+        R: AsyncRead + Send + Unpin + 'static,
+    {
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(upload_url)
+            .header("content-type", "application/octet-stream")
+            .header("content-length", length)
+            .body(Body::wrap_stream(read_chunks(file)))?;
+        let resp = self.client.request(request).await?;
+        debug!("response - {:#?}", resp);
+        let status = resp.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        let body = hyper::body::to_bytes(resp.into_body()).await?;
+        Err(Error::Http {
+            status,
+            body: String::from_utf8_lossy(&body).into_owned(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use futures::TryStreamExt;
+    use std::io::Cursor;
+
+    fn collect(stream: impl Stream<Item = io::Result<Bytes>>) -> String {
+        let chunks: Vec<Bytes> = block_on(stream.try_collect()).unwrap();
+        String::from_utf8(chunks.concat()).unwrap()
+    }
+
+    #[test]
+    fn read_chunks_reads_everything() {
+        let data = "x".repeat(CHUNK_SIZE + 10);
+        let chunks: Vec<Bytes> =
+            block_on(read_chunks(Cursor::new(data.clone().into_bytes())).try_collect()).unwrap();
+        assert_eq!(2, chunks.len());
+        assert_eq!(data.as_bytes(), &chunks.concat()[..]);
+    }
+
+    #[tokio::test]
+    async fn download_refuses_other_hosts() {
+        let client = SlackApiClient::new("xoxb");
+        for url in &[
+            "https://example.com/files-pri/T1-F1/report.pdf",
+            "http://files.slack.com/files-pri/T1-F1/report.pdf",
+            "https://files.slack.com.example.com/files-pri/T1-F1/report.pdf",
+            "https://files.slack.com:8443/files-pri/T1-F1/report.pdf",
+        ] {
+            match client.download(url).await {
+                Err(Error::UntrustedUrl(refused)) => assert_eq!(url, &refused),
+                other => panic!("unexpected {:?}", other.map(|_| ())),
+            }
+        }
+    }
+
+    #[test]
+    fn multipart_works() {
+        let (content_type, body) = multipart(
+            vec![("channels".to_string(), "C1,C2".to_string())],
+            "echo \"event\".json",
+            Cursor::new(b"{}".to_vec()),
+        );
+        let boundary = content_type.trim_start_matches("multipart/form-data; boundary=");
+        assert_eq!(
+            format!(
+                "--{b}\r\n\
+                 Content-Disposition: form-data; name=\"channels\"\r\n\r\n\
+                 C1,C2\r\n\
+                 --{b}\r\n\
+                 Content-Disposition: form-data; name=\"file\"; filename=\"echo %22event%22.json\"\r\n\
+                 Content-Type: application/octet-stream\r\n\r\n\
+                 {{}}\r\n\
+                 --{b}--\r\n",
+                b = boundary
+            ),
+            collect(body)
+        );
+    }
+}
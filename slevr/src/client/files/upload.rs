@@ -0,0 +1,55 @@
+use super::multipart;
+use crate::events::File;
+use crate::form;
+use crate::{ChannelId, Result, SlackApiClient, Ts};
+use hyper::Body;
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncRead;
+
+#[derive(Serialize, Debug, Default)]
+pub struct FilesUpload {
+    /// Where to share the file, it's private to the uploader otherwise.
+    pub channels: Option<Vec<ChannelId>>,
+    /// The text of a snippet. Ignored when uploading from a reader.
+    pub content: Option<String>,
+    pub filename: Option<String>,
+    /// Slack's name for the type, e.g. `text` or `json`. Guessed from the contents if missing.
+    pub filetype: Option<String>,
+    /// The message to share the file with
+    pub initial_comment: Option<String>,
+    /// Share it as a reply in this thread
+    pub thread_ts: Option<Ts>,
+    pub title: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct FilesUploadResponse {
+    pub file: File,
+}
+
+impl SlackApiClient {
+    /// Uploads `content` as a snippet.
+    pub async fn files_upload(&self, upload: FilesUpload) -> Result<FilesUploadResponse> {
+        self.post_form("files.upload", &upload).await
+    }
+
+    /// Uploads everything `file` has to read, without holding it all in memory. The reader
+    /// can only be read once, so unlike other methods this one isn't retried.
+    pub async fn files_upload_from<R>(
+        &self,
+        mut upload: FilesUpload,
+        file: R,
+    ) -> Result<FilesUploadResponse>
+    where
+        R: AsyncRead + Send + Unpin + 'static,
+    {
+        upload.content = None;
+        let filename = upload
+            .filename
+            .clone()
+            .unwrap_or_else(|| "file".to_string());
+        let (content_type, body) = multipart(form::fields(&upload)?, &filename, file);
+        self.post_once("files.upload", &content_type, Body::wrap_stream(body))
+            .await
+    }
+}
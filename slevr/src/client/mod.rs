@@ -1,11 +1,38 @@
-use hyper::{client::HttpConnector, Body, Client};
+use crate::error::{parse_response, Error, Result};
+use crate::form;
+use hyper::{client::HttpConnector, Body, Client, Method, Request, StatusCode};
 use hyper_rustls::HttpsConnector;
+use log::debug;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
+// First, so the list methods can use its `paginated!` macro.
+#[macro_use]
+pub mod paginate;
 pub mod chat;
+pub mod conversations;
+pub mod files;
+pub mod reactions;
+pub mod response_url;
+pub mod retry;
+pub(crate) mod unix_time;
+pub mod users;
+pub mod views;
+
+pub use paginate::{Page, Paginated, Paginator};
+pub use retry::{RetryPolicy, Tier};
+
+/// Returned by list methods that support cursor pagination.
+#[derive(Deserialize, Debug, Default)]
+pub struct ResponseMetadata {
+    /// Empty or missing once there's nothing left to fetch.
+    pub next_cursor: Option<String>,
+}
 
 pub struct SlackApiClient {
     client: Client<HttpsConnector<HttpConnector>>,
     oauth: String,
+    retry: RetryPolicy,
+    budget: retry::TierBudget,
 }
 
 impl SlackApiClient {
@@ -13,6 +40,128 @@ impl SlackApiClient {
         SlackApiClient {
             client: Client::builder().build::<_, Body>(HttpsConnector::new()),
             oauth: oauth.to_string(),
+            retry: RetryPolicy::default(),
+            budget: Default::default(),
+        }
+    }
+
+    /// Replaces the default retry behaviour, `RetryPolicy { max_retries: 0, .. }` turns it off.
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Overrides the rate limit budget for a single Web API method, e.g. when Slack
+    /// has granted your app a higher tier than the documented default.
+    pub fn with_tier(mut self, method: &str, tier: Tier) -> Self {
+        self.budget.set_tier(method, tier);
+        self
+    }
+
+    /// POSTs `body` as JSON to the Web API `method` and decodes the response,
+    /// waiting out rate limits and retrying transient failures.
+    pub(crate) async fn post<B, R>(&self, method: &str, body: &B) -> Result<R>
+    where
+        B: Serialize,
+        R: DeserializeOwned,
+    {
+        let body = serde_json::to_string(body)?;
+        self.call(method, "application/json; charset=utf-8", body)
+            .await
+    }
+
+    /// Like `post`, but form encoded. Read methods like conversations.history don't
+    /// accept JSON bodies.
+    pub(crate) async fn post_form<B, R>(&self, method: &str, body: &B) -> Result<R>
+    where
+        B: Serialize,
+        R: DeserializeOwned,
+    {
+        let body = form::to_string(body)?;
+        self.call(method, form::CONTENT_TYPE, body).await
+    }
+
+    async fn call<R>(&self, method: &str, content_type: &str, body: String) -> Result<R>
+    where
+        R: DeserializeOwned,
+    {
+        let mut attempt = 0;
+        loop {
+            let wait = self.budget.reserve(method);
+            if wait.as_nanos() > 0 {
+                debug!("waiting {:?} for {} budget", wait, method);
+                tokio::time::delay_for(wait).await;
+            }
+
+            let err = match self
+                .send(method, content_type, Body::from(body.clone()))
+                .await
+            {
+                Ok(response) => return Ok(response),
+                Err(err) => err,
+            };
+            let delay = match self
+                .retry
+                .delay_for(&err, attempt, retry::idempotent(method))
+            {
+                Some(delay) => delay,
+                None => return Err(err),
+            };
+            debug!("retrying {} in {:?} after {}", method, delay, err);
+            if let Error::RateLimited { .. } = err {
+                self.budget.block(method, delay);
+            } else {
+                tokio::time::delay_for(delay).await;
+            }
+            attempt += 1;
+        }
+    }
+
+    /// Like `post`, for bodies that can only be sent once, such as a file streamed from a
+    /// reader. The method's rate limit budget is still waited out, but nothing is retried.
+    pub(crate) async fn post_once<R>(
+        &self,
+        method: &str,
+        content_type: &str,
+        body: Body,
+    ) -> Result<R>
+    where
+        R: DeserializeOwned,
+    {
+        let wait = self.budget.reserve(method);
+        if wait.as_nanos() > 0 {
+            debug!("waiting {:?} for {} budget", wait, method);
+            tokio::time::delay_for(wait).await;
+        }
+        self.send(method, content_type, body).await
+    }
+
+    async fn send<R>(&self, method: &str, content_type: &str, body: Body) -> Result<R>
+    where
+        R: DeserializeOwned,
+    {
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(format!("https://slack.com/api/{}", method))
+            .header("content-type", content_type)
+            .header("Authorization", format!("Bearer {}", self.oauth))
+            .body(body)?;
+        debug!("request - {:#?}", request);
+        let resp = self.client.request(request).await?;
+        debug!("response - {:#?}", resp);
+        let status = resp.status();
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            return Err(Error::RateLimited {
+                retry_after: retry::retry_after(resp.headers()),
+            });
+        }
+        let body = hyper::body::to_bytes(resp.into_body()).await?;
+        if !status.is_success() {
+            return Err(Error::Http {
+                status,
+                body: String::from_utf8_lossy(&body).into_owned(),
+            });
         }
+        parse_response(&body)
     }
 }
@@ -0,0 +1,292 @@
+//! Cursor pagination for list methods like conversations.list, as a `Stream` of items.
+//! Every page goes through the client, so pages are paced by the method's rate limit tier
+//! and 429s are waited out before the stream moves on.
+//! More info - https://api.slack.com/docs/pagination
+use crate::{Error, ResponseMetadata, Result, SlackApiClient};
+use futures::future::BoxFuture;
+use futures::stream::{self, Stream, TryStreamExt};
+use std::convert::TryFrom;
+use std::future::Future;
+
+/// One page of a list method's results.
+#[derive(Debug)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Where the next page starts, `None` on the last page.
+    pub next_cursor: Option<String>,
+}
+
+impl<T> Page<T> {
+    pub fn new(items: Vec<T>, metadata: Option<ResponseMetadata>) -> Self {
+        Page {
+            items,
+            // Slack signals the last page with an empty cursor as often as a missing one.
+            next_cursor: metadata
+                .and_then(|metadata| metadata.next_cursor)
+                .filter(|cursor| !cursor.is_empty()),
+        }
+    }
+}
+
+/// A request to a cursor paginated list method.
+pub trait Paginated: Clone + Send + 'static {
+    type Item: Send + 'static;
+
+    fn set_cursor(&mut self, cursor: Option<String>);
+
+    fn set_limit(&mut self, limit: u32);
+
+    /// Fetches the page this request points at.
+    fn fetch(self, client: &SlackApiClient) -> BoxFuture<'_, Result<Page<Self::Item>>>;
+}
+
+/// Implements `Paginated` for a request with `cursor` and `limit` fields, e.g.
+/// `paginated!(UsersList => users_list, members: User);` fetches pages with
+/// `SlackApiClient::users_list` and takes the items from the response's `members`.
+macro_rules! paginated {
+    ($request:ty => $method:ident, $field:ident: $item:ty) => {
+        impl $crate::client::paginate::Paginated for $request {
+            type Item = $item;
+
+            fn set_cursor(&mut self, cursor: Option<String>) {
+                self.cursor = cursor;
+            }
+
+            fn set_limit(&mut self, limit: u32) {
+                self.limit = Some(limit);
+            }
+
+            fn fetch(
+                self,
+                client: &$crate::SlackApiClient,
+            ) -> futures::future::BoxFuture<
+                '_,
+                $crate::Result<$crate::client::paginate::Page<Self::Item>>,
+            > {
+                Box::pin(async move {
+                    let response = client.$method(self).await?;
+                    Ok($crate::client::paginate::Page::new(
+                        response.$field,
+                        response.response_metadata,
+                    ))
+                })
+            }
+        }
+    };
+}
+
+/// Builds a stream over every page of a request, see `SlackApiClient::paginate`.
+pub struct Paginator<'a, R> {
+    client: &'a SlackApiClient,
+    request: R,
+    page_size: Option<u32>,
+    max_items: Option<usize>,
+}
+
+impl<'a, R: Paginated> Paginator<'a, R> {
+    /// How many items to ask for per page. Slack recommends no more than 200, and
+    /// otherwise each method's own default applies.
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Stops after this many items, without fetching pages that aren't needed.
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Starts from a cursor returned by an earlier request instead of the first page.
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.request.set_cursor(Some(cursor.into()));
+        self
+    }
+
+    /// The pages, one at a time. The stream ends after the first error.
+    pub fn pages(self) -> impl Stream<Item = Result<Page<R::Item>>> + 'a {
+        let client = self.client;
+        pages(
+            self.request,
+            self.page_size,
+            self.max_items,
+            move |request| request.fetch(client),
+        )
+    }
+
+    /// The items of every page in order. The stream ends after the first error.
+    pub fn items(self) -> impl Stream<Item = Result<R::Item>> + 'a {
+        self.pages()
+            .map_ok(|page| stream::iter(page.items.into_iter().map(Ok::<_, Error>)))
+            .try_flatten()
+    }
+}
+
+struct State<R, F> {
+    request: R,
+    fetch: F,
+    remaining: Option<usize>,
+    done: bool,
+}
+
+fn pages<R, F, Fut>(
+    request: R,
+    page_size: Option<u32>,
+    max_items: Option<usize>,
+    fetch: F,
+) -> impl Stream<Item = Result<Page<R::Item>>>
+where
+    R: Paginated,
+    F: Fn(R) -> Fut,
+    Fut: Future<Output = Result<Page<R::Item>>>,
+{
+    let state = State {
+        request,
+        fetch,
+        remaining: max_items,
+        done: false,
+    };
+    stream::unfold(state, move |mut state| async move {
+        if state.done || state.remaining == Some(0) {
+            return None;
+        }
+        let limit = match (page_size, state.remaining) {
+            (Some(size), Some(remaining)) => {
+                Some(u32::try_from(remaining).map_or(size, |remaining| size.min(remaining)))
+            }
+            (size, _) => size,
+        };
+        if let Some(limit) = limit {
+            state.request.set_limit(limit);
+        }
+        let mut page = match (state.fetch)(state.request.clone()).await {
+            Ok(page) => page,
+            Err(err) => {
+                state.done = true;
+                return Some((Err(err), state));
+            }
+        };
+        if let Some(remaining) = state.remaining.as_mut() {
+            page.items.truncate(*remaining);
+            *remaining -= page.items.len();
+        }
+        state.done = page.next_cursor.is_none();
+        state.request.set_cursor(page.next_cursor.clone());
+        Some((Ok(page), state))
+    })
+}
+
+impl SlackApiClient {
+    /// Pages through a list method, e.g.
+    /// `client.paginate(ConversationsList::default()).page_size(200).items()`.
+    pub fn paginate<R: Paginated>(&self, request: R) -> Paginator<'_, R> {
+        Paginator {
+            client: self,
+            request,
+            page_size: None,
+            max_items: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ApiErrorCode;
+    use futures::executor::block_on;
+    use futures::StreamExt;
+
+    #[derive(Clone, Default, Debug)]
+    struct Numbers {
+        cursor: Option<String>,
+        limit: Option<u32>,
+    }
+
+    impl Paginated for Numbers {
+        type Item = u32;
+
+        fn set_cursor(&mut self, cursor: Option<String>) {
+            self.cursor = cursor;
+        }
+
+        fn set_limit(&mut self, limit: u32) {
+            self.limit = Some(limit);
+        }
+
+        fn fetch(self, _: &SlackApiClient) -> BoxFuture<'_, Result<Page<u32>>> {
+            unreachable!()
+        }
+    }
+
+    /// Pages through 0..total, failing when asked to start at `fail_at`.
+    fn numbers(
+        total: u32,
+        fail_at: Option<u32>,
+    ) -> impl Fn(Numbers) -> futures::future::Ready<Result<Page<u32>>> {
+        move |request| {
+            let start = request.cursor.map_or(0, |cursor| cursor.parse().unwrap());
+            if Some(start) == fail_at {
+                return futures::future::ready(Err(Error::Api(ApiErrorCode::RateLimited)));
+            }
+            let end = (start + request.limit.unwrap_or(3)).min(total);
+            let next = Some(end.to_string()).filter(|_| end < total);
+            futures::future::ready(Ok(Page::new(
+                (start..end).collect(),
+                Some(ResponseMetadata {
+                    next_cursor: Some(next.unwrap_or_default()),
+                }),
+            )))
+        }
+    }
+
+    fn collect(stream: impl Stream<Item = Result<Page<u32>>>) -> Vec<Result<Vec<u32>>> {
+        block_on(stream.map_ok(|page| page.items).collect())
+    }
+
+    #[test]
+    fn pages_follow_the_cursor() {
+        let got = collect(pages(Numbers::default(), None, None, numbers(7, None)));
+        let got = got.into_iter().collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]], got);
+
+        let got = collect(pages(Numbers::default(), Some(4), None, numbers(7, None)));
+        let got = got.into_iter().collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(vec![vec![0, 1, 2, 3], vec![4, 5, 6]], got);
+    }
+
+    #[test]
+    fn max_items_stops_early() {
+        let got = collect(pages(
+            Numbers::default(),
+            Some(4),
+            Some(6),
+            numbers(100, None),
+        ));
+        let got = got.into_iter().collect::<Result<Vec<_>>>().unwrap();
+        // The last page only asks for what's left.
+        assert_eq!(vec![vec![0, 1, 2, 3], vec![4, 5]], got);
+
+        // Without a page size the extra items are dropped instead.
+        let got = collect(pages(Numbers::default(), None, Some(4), numbers(100, None)));
+        let got = got.into_iter().collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(vec![vec![0, 1, 2], vec![3]], got);
+
+        // More than fits in a limit still uses the page size.
+        let max = u32::MAX as usize + 1;
+        let got =
+            collect(pages(Numbers::default(), Some(4), Some(max), numbers(100, None)).take(2));
+        let got = got.into_iter().collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(vec![vec![0, 1, 2, 3], vec![4, 5, 6, 7]], got);
+    }
+
+    #[test]
+    fn errors_end_the_stream() {
+        let got = collect(pages(Numbers::default(), None, None, numbers(100, Some(3))));
+        assert_eq!(2, got.len());
+        assert_eq!(vec![0, 1, 2], *got[0].as_ref().unwrap());
+        match &got[1] {
+            Err(Error::Api(ApiErrorCode::RateLimited)) => {}
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use super::ReactionTarget;
+use crate::{Result, SlackApiClient};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Default)]
+pub struct ReactionsAdd {
+    /// Only messages can be reacted to anymore, files and file comments are deprecated.
+    #[serde(flatten)]
+    pub target: ReactionTarget,
+    /// The emoji name, without colons
+    pub name: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ReactionsAddResponse {}
+
+impl SlackApiClient {
+    /// Fails with already_reacted if the caller has already used this emoji on the item.
+    pub async fn reactions_add(&self, add: ReactionsAdd) -> Result<ReactionsAddResponse> {
+        self.post("reactions.add", &add).await
+    }
+}
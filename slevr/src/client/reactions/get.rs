@@ -0,0 +1,23 @@
+use super::{ReactedItem, ReactionTarget};
+use crate::{Result, SlackApiClient};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Default)]
+pub struct ReactionsGet {
+    #[serde(flatten)]
+    pub target: ReactionTarget,
+    /// If true always return the complete reaction list.
+    pub full: Option<bool>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ReactionsGetResponse {
+    #[serde(flatten)]
+    pub item: ReactedItem,
+}
+
+impl SlackApiClient {
+    pub async fn reactions_get(&self, get: ReactionsGet) -> Result<ReactionsGetResponse> {
+        self.post_form("reactions.get", &get).await
+    }
+}
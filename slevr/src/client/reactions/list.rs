@@ -0,0 +1,33 @@
+use super::ReactedItem;
+use crate::{ResponseMetadata, Result, SlackApiClient, TeamId, UserId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ReactionsList {
+    /// Show reactions made by this user. Defaults to the calling user.
+    pub user: Option<UserId>,
+    /// If true always return the complete reaction list.
+    pub full: Option<bool>,
+    /// Paginate through collections of data by setting the cursor parameter to a
+    /// next_cursor attribute returned by a previous request's response_metadata.
+    pub cursor: Option<String>,
+    /// The maximum number of items to return.
+    pub limit: Option<u32>,
+    /// Required for org-wide apps
+    pub team_id: Option<TeamId>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ReactionsListResponse {
+    pub items: Vec<ReactedItem>,
+    pub response_metadata: Option<ResponseMetadata>,
+}
+
+impl SlackApiClient {
+    /// Lists the items a user has reacted to.
+    pub async fn reactions_list(&self, list: ReactionsList) -> Result<ReactionsListResponse> {
+        self.post_form("reactions.list", &list).await
+    }
+}
+
+paginated!(ReactionsList => reactions_list, items: ReactedItem);
@@ -0,0 +1,151 @@
+//! Emoji reactions on messages and files. The reaction_added and reaction_removed events
+//! say what was reacted to with an `events::Item`, which converts to a `ReactionTarget`.
+//! More info - https://api.slack.com/methods/reactions.add
+use crate::conversations::HistoryMessage;
+use crate::events::Item;
+use crate::{ChannelId, Ts, UserId};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub mod add;
+pub mod get;
+pub mod list;
+pub mod remove;
+
+/// One emoji's reactions on an item.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Reaction {
+    /// The emoji name, without colons
+    pub name: String,
+    pub count: u32,
+    /// Who reacted, which may be cut short when count is high unless `full` was asked for.
+    pub users: Vec<UserId>,
+}
+
+/// What to add, remove or look up reactions on.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum ReactionTarget {
+    Message { channel: ChannelId, timestamp: Ts },
+    File { file: String },
+    FileComment { file_comment: String },
+}
+
+impl Default for ReactionTarget {
+    fn default() -> Self {
+        ReactionTarget::Message {
+            channel: ChannelId::default(),
+            timestamp: Ts::default(),
+        }
+    }
+}
+
+impl ReactionTarget {
+    pub fn message(channel: ChannelId, timestamp: Ts) -> Self {
+        ReactionTarget::Message { channel, timestamp }
+    }
+}
+
+/// The item from a reaction event, so a bot can react to the same thing.
+impl From<&Item> for ReactionTarget {
+    fn from(item: &Item) -> Self {
+        match item {
+            Item::Message { channel, ts } => ReactionTarget::Message {
+                channel: channel.clone(),
+                timestamp: ts.clone(),
+            },
+            Item::File { file } => ReactionTarget::File { file: file.clone() },
+            Item::FileComment { file_comment, .. } => ReactionTarget::FileComment {
+                file_comment: file_comment.clone(),
+            },
+        }
+    }
+}
+
+/// Something reactions.get or reactions.list found, with its reactions.
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ReactedItem {
+    /// The reactions are on the message.
+    Message {
+        channel: ChannelId,
+        message: HistoryMessage,
+    },
+    /// The reactions are on the file object, under `reactions`.
+    File { file: Value },
+    /// The reactions are on the comment object, under `reactions`.
+    FileComment { file: Value, comment: Value },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{MessageSubtype, UserMessage};
+
+    #[test]
+    fn reaction_target_works() {
+        let item = Item::Message {
+            channel: "C0G9QF9GZ".into(),
+            ts: "1360782400.498405".parse().unwrap(),
+        };
+        assert_eq!(
+            serde_json::json!({"channel": "C0G9QF9GZ", "timestamp": "1360782400.498405"}),
+            serde_json::to_value(ReactionTarget::from(&item)).unwrap()
+        );
+        assert_eq!(
+            "channel=C0G9QF9GZ&full=true&timestamp=1360782400.498405",
+            crate::form::to_string(&get::ReactionsGet {
+                target: ReactionTarget::from(&item),
+                full: Some(true),
+            })
+            .unwrap()
+        );
+        assert_eq!(
+            serde_json::json!({"file": "F0HS27V1Z"}),
+            serde_json::to_value(ReactionTarget::from(&Item::File {
+                file: "F0HS27V1Z".into()
+            }))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn reacted_item_works() {
+        let item: ReactedItem = serde_json::from_str(
+            r#"{
+                "type": "message",
+                "channel": "C2147483705",
+                "message": {
+                    "type": "message",
+                    "user": "U2147483697",
+                    "text": "Hello world",
+                    "ts": "1355517523.000005",
+                    "reactions": [
+                        {"name": "white_check_mark", "count": 2, "users": ["U1", "U2"]}
+                    ]
+                }
+            }"#,
+        )
+        .unwrap();
+        match item {
+            ReactedItem::Message { channel, message } => {
+                assert_eq!("C2147483705", channel);
+                assert_eq!(
+                    vec![Reaction {
+                        name: "white_check_mark".to_string(),
+                        count: 2,
+                        users: vec!["U1".into(), "U2".into()],
+                    }],
+                    message.reactions
+                );
+                match message.subtype {
+                    MessageSubtype::Plain(UserMessage { text, .. }) => {
+                        assert_eq!("Hello world", text)
+                    }
+                    other => panic!("unexpected {:?}", other),
+                }
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
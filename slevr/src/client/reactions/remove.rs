@@ -0,0 +1,24 @@
+use super::ReactionTarget;
+use crate::{Result, SlackApiClient};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Default)]
+pub struct ReactionsRemove {
+    #[serde(flatten)]
+    pub target: ReactionTarget,
+    /// The emoji name, without colons
+    pub name: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ReactionsRemoveResponse {}
+
+impl SlackApiClient {
+    /// Fails with no_reaction if the caller hadn't used this emoji on the item.
+    pub async fn reactions_remove(
+        &self,
+        remove: ReactionsRemove,
+    ) -> Result<ReactionsRemoveResponse> {
+        self.post("reactions.remove", &remove).await
+    }
+}
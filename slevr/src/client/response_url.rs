@@ -0,0 +1,198 @@
+//! Delayed replies through the `response_url` Slack hands out with slash commands and
+//! interactive messages. A response_url works for 30 minutes, at most 5 times, and doesn't
+//! need a token.
+//! More info - https://api.slack.com/interactivity/handling#message_responses
+use super::retry;
+use crate::blocks::Block;
+use crate::chat::post_message::ChatMessage;
+use crate::commands::{ResponseType, SlashResponse};
+use crate::error::{ApiErrorCode, ApiStatus, Error, Result};
+use crate::{SlackApiClient, Ts};
+use hyper::{Body, Method, Request, StatusCode};
+use log::debug;
+use serde::Serialize;
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct ResponseMessage {
+    /// Defaults to ephemeral for new messages. Ignored when replacing the original.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_type: Option<ResponseType>,
+    /// The message, or the fallback for notifications when there are blocks.
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<Block>>,
+    /// Replace the message the interaction came from instead of posting a new one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replace_original: Option<bool>,
+    /// Delete the message the interaction came from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete_original: Option<bool>,
+    /// Reply in this thread.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<Ts>,
+}
+
+impl ResponseMessage {
+    /// A new message only the user who triggered the interaction sees.
+    pub fn ephemeral(text: impl Into<String>) -> Self {
+        ResponseMessage {
+            response_type: Some(ResponseType::Ephemeral),
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+
+    /// A new message everyone in the channel sees.
+    pub fn in_channel(text: impl Into<String>) -> Self {
+        ResponseMessage {
+            response_type: Some(ResponseType::InChannel),
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Replaces the message the interaction came from.
+    pub fn replace_original(text: impl Into<String>) -> Self {
+        ResponseMessage {
+            text: text.into(),
+            replace_original: Some(true),
+            ..Default::default()
+        }
+    }
+
+    /// Deletes the message the interaction came from.
+    pub fn delete_original() -> Self {
+        ResponseMessage {
+            delete_original: Some(true),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_blocks(mut self, blocks: Vec<Block>) -> Self {
+        self.blocks = Some(blocks);
+        self
+    }
+}
+
+impl From<SlashResponse> for ResponseMessage {
+    fn from(response: SlashResponse) -> Self {
+        ResponseMessage {
+            response_type: Some(response.response_type),
+            text: response.text,
+            blocks: response.blocks,
+            ..Default::default()
+        }
+    }
+}
+
+/// Posts a chat message's text, blocks and thread into the channel the response_url
+/// belongs to.
+impl From<ChatMessage> for ResponseMessage {
+    fn from(message: ChatMessage) -> Self {
+        ResponseMessage {
+            response_type: Some(ResponseType::InChannel),
+            text: message.text,
+            blocks: message.blocks,
+            thread_ts: message.thread_ts,
+            ..Default::default()
+        }
+    }
+}
+
+/// response_url errors come back as a plain-text or JSON body, with or without an error
+/// status depending on the error.
+fn parse_response(status: StatusCode, body: &[u8]) -> Result<()> {
+    match serde_json::from_slice::<ApiStatus>(body) {
+        Ok(ApiStatus { ok: true, .. }) => Ok(()),
+        Ok(ApiStatus { error, .. }) => Err(Error::Api(ApiErrorCode::from(
+            error.unwrap_or_else(|| "unknown_error".to_string()),
+        ))),
+        Err(_) if status.is_success() => Ok(()),
+        Err(_) => Err(Error::Http {
+            status,
+            body: String::from_utf8_lossy(body).into_owned(),
+        }),
+    }
+}
+
+impl SlackApiClient {
+    /// Sends `message` to a response_url. Only rate limits are retried, since a retry after
+    /// anything else could post the message twice and use up the response_url.
+    pub async fn respond(&self, response_url: &str, message: &ResponseMessage) -> Result<()> {
+        let body = serde_json::to_string(message)?;
+        let mut attempt = 0;
+        loop {
+            let err = match self.send_response(response_url, body.clone()).await {
+                Ok(()) => return Ok(()),
+                Err(err @ Error::RateLimited { .. }) => err,
+                Err(err) => return Err(err),
+            };
+            let delay = match self.retry.delay_for(&err, attempt, false) {
+                Some(delay) => delay,
+                None => return Err(err),
+            };
+            debug!("retrying response in {:?} after {}", delay, err);
+            tokio::time::delay_for(delay).await;
+            attempt += 1;
+        }
+    }
+
+    async fn send_response(&self, response_url: &str, body: String) -> Result<()> {
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(response_url)
+            .header("content-type", "application/json; charset=utf-8")
+            .body(Body::from(body))?;
+        let resp = self.client.request(request).await?;
+        debug!("response - {:#?}", resp);
+        let status = resp.status();
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            return Err(Error::RateLimited {
+                retry_after: retry::retry_after(resp.headers()),
+            });
+        }
+        let body = hyper::body::to_bytes(resp.into_body()).await?;
+        parse_response(status, &body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn response_message_works() {
+        assert_eq!(
+            r#"{"text":"Done!","replace_original":true}"#,
+            serde_json::to_string(&ResponseMessage::replace_original("Done!")).unwrap()
+        );
+        assert_eq!(
+            r#"{"text":"","delete_original":true}"#,
+            serde_json::to_string(&ResponseMessage::delete_original()).unwrap()
+        );
+        assert_eq!(
+            ResponseMessage::in_channel("It's 80 degrees right now."),
+            SlashResponse::in_channel("It's 80 degrees right now.").into()
+        );
+    }
+
+    #[test]
+    fn response_errors_parse() {
+        assert!(parse_response(StatusCode::OK, b"ok").is_ok());
+        assert!(parse_response(StatusCode::OK, br#"{"ok":true}"#).is_ok());
+        match parse_response(
+            StatusCode::NOT_FOUND,
+            br#"{"ok":false,"error":"expired_url"}"#,
+        ) {
+            Err(Error::Api(ApiErrorCode::Other(code))) => assert_eq!("expired_url", code),
+            other => panic!("unexpected {:?}", other),
+        }
+        match parse_response(StatusCode::NOT_FOUND, b"used_url") {
+            Err(Error::Http { status, body }) => {
+                assert_eq!(StatusCode::NOT_FOUND, status);
+                assert_eq!("used_url", body);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,379 @@
+use crate::{ApiErrorCode, Error};
+use hyper::{header::RETRY_AFTER, HeaderMap};
+use rand::Rng;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Slack's published rate limit tiers.
+/// More info - https://api.slack.com/docs/rate-limits#tiers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tier {
+    /// 1+ per minute
+    Tier1,
+    /// 20+ per minute
+    Tier2,
+    /// 50+ per minute
+    Tier3,
+    /// 100+ per minute
+    Tier4,
+    /// Methods like chat.postMessage that don't fit a tier. A `per_minute` of 0 is
+    /// treated as 1.
+    Custom { per_minute: u32 },
+}
+
+impl Tier {
+    pub fn per_minute(self) -> u32 {
+        match self {
+            Tier::Tier1 => 1,
+            Tier::Tier2 => 20,
+            Tier::Tier3 => 50,
+            Tier::Tier4 => 100,
+            // A bucket that never refills would have to wait forever.
+            Tier::Custom { per_minute } => per_minute.max(1),
+        }
+    }
+
+    /// The tier Slack documents for a Web API method.
+    pub fn for_method(method: &str) -> Tier {
+        match method {
+            // Roughly one message per second per channel, with some burst allowed.
+            "chat.postMessage" => Tier::Custom { per_minute: 60 },
+            "chat.postEphemeral" => Tier::Tier4,
+            "chat.update" | "chat.delete" => Tier::Tier3,
+            "views.open" | "views.push" | "views.update" | "views.publish" => Tier::Tier4,
+            "conversations.list"
+            | "conversations.create"
+            | "conversations.archive"
+            | "conversations.setTopic"
+            | "conversations.setPurpose" => Tier::Tier2,
+            "conversations.members" => Tier::Tier4,
+            "users.list" => Tier::Tier2,
+            "reactions.remove" | "reactions.list" => Tier::Tier2,
+            "files.upload" => Tier::Tier2,
+            "files.getUploadURLExternal" | "files.completeUploadExternal" | "files.info" => {
+                Tier::Tier4
+            }
+            "users.info" | "users.profile.get" => Tier::Tier4,
+            _ => Tier::Tier3,
+        }
+    }
+}
+
+/// How hard to try before giving up on a request.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt; 0 disables retrying.
+    pub max_retries: u32,
+    /// Starting point for the exponential backoff.
+    pub base_delay: Duration,
+    /// Upper bound for any single backoff. A `Retry-After` longer than this isn't waited
+    /// out, the request fails with `Error::RateLimited` instead.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Full jitter: a random delay between zero and `base_delay * 2^attempt`.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let ceiling = self
+            .base_delay
+            .checked_mul(1 << attempt.min(16))
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+        let millis = ceiling.as_millis() as u64;
+        Duration::from_millis(rand::thread_rng().gen_range(0, millis + 1))
+    }
+
+    /// How long to wait before retrying after `err`, or `None` if it isn't worth retrying.
+    /// Rate limits and failed connections mean Slack never acted on the request, so those
+    /// are always retried, unless `Retry-After` asks for more than `max_delay`. Server
+    /// errors and broken connections may have come after the request took effect, so
+    /// they're only retried when `idempotent` says a second call couldn't post a message
+    /// twice.
+    pub(crate) fn delay_for(
+        &self,
+        err: &Error,
+        attempt: u32,
+        idempotent: bool,
+    ) -> Option<Duration> {
+        if attempt >= self.max_retries {
+            return None;
+        }
+        match err {
+            // Retrying any sooner would only get another 429.
+            Error::RateLimited {
+                retry_after: Some(retry_after),
+            } if *retry_after <= self.max_delay => Some(*retry_after),
+            Error::RateLimited {
+                retry_after: Some(_),
+            } => None,
+            Error::RateLimited { retry_after: None } | Error::Api(ApiErrorCode::RateLimited) => {
+                Some(self.backoff(attempt))
+            }
+            Error::Transport(err) if err.is_connect() => Some(self.backoff(attempt)),
+            Error::Transport(_)
+            | Error::Api(ApiErrorCode::InternalError)
+            | Error::Api(ApiErrorCode::RequestTimeout)
+            | Error::Api(ApiErrorCode::ServiceUnavailable)
+                if idempotent =>
+            {
+                Some(self.backoff(attempt))
+            }
+            Error::Http { status, .. } if status.is_server_error() && idempotent => {
+                Some(self.backoff(attempt))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Whether calling `method` twice has the same effect as calling it once, which is true of
+/// the read methods and a few writes that set something to a given value.
+pub(crate) fn idempotent(method: &str) -> bool {
+    matches!(
+        method,
+        "conversations.history"
+            | "conversations.info"
+            | "conversations.list"
+            | "conversations.members"
+            | "conversations.replies"
+            | "users.info"
+            | "users.list"
+            | "users.lookupByEmail"
+            | "users.getPresence"
+            | "users.profile.get"
+            | "reactions.get"
+            | "reactions.list"
+            | "files.info"
+            | "chat.scheduledMessages.list"
+            | "conversations.open"
+            | "conversations.setTopic"
+            | "conversations.setPurpose"
+            | "views.publish"
+            | "views.update"
+    )
+}
+
+/// Reads `Retry-After`, which Slack always sends as a number of seconds.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tier: Tier,
+    tokens: f64,
+    updated: Instant,
+    blocked_until: Option<Instant>,
+}
+
+impl Bucket {
+    fn new(tier: Tier, now: Instant) -> Self {
+        Bucket {
+            tier,
+            tokens: f64::from(tier.per_minute()),
+            updated: now,
+            blocked_until: None,
+        }
+    }
+
+    /// Takes a token, returning how long the caller has to wait before it's theirs.
+    fn reserve(&mut self, now: Instant) -> Duration {
+        let capacity = f64::from(self.tier.per_minute());
+        let per_second = capacity / 60.0;
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * per_second).min(capacity) - 1.0;
+        self.updated = now;
+
+        let refill = if self.tokens < 0.0 {
+            Duration::from_secs_f64(-self.tokens / per_second)
+        } else {
+            Duration::from_secs(0)
+        };
+        let blocked = self
+            .blocked_until
+            .map(|until| until.saturating_duration_since(now))
+            .unwrap_or_else(|| Duration::from_secs(0));
+        refill.max(blocked)
+    }
+}
+
+/// Keeps every method within its tier, so a burst of calls queues up locally
+/// instead of running into 429s.
+#[derive(Debug, Default)]
+pub(crate) struct TierBudget {
+    overrides: HashMap<String, Tier>,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+impl TierBudget {
+    pub(crate) fn set_tier(&mut self, method: &str, tier: Tier) {
+        self.overrides.insert(method.to_string(), tier);
+    }
+
+    fn tier(&self, method: &str) -> Tier {
+        self.overrides
+            .get(method)
+            .copied()
+            .unwrap_or_else(|| Tier::for_method(method))
+    }
+
+    pub(crate) fn reserve(&self, method: &str) -> Duration {
+        let now = Instant::now();
+        let tier = self.tier(method);
+        let mut buckets = self.buckets.lock().unwrap();
+        buckets
+            .entry(method.to_string())
+            .or_insert_with(|| Bucket::new(tier, now))
+            .reserve(now)
+    }
+
+    /// Slack told us to back off, so hold everyone else calling `method` too.
+    pub(crate) fn block(&self, method: &str, delay: Duration) {
+        let now = Instant::now();
+        let tier = self.tier(method);
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets
+            .entry(method.to_string())
+            .or_insert_with(|| Bucket::new(tier, now));
+        bucket.blocked_until = Some(now + delay);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hyper::header::HeaderValue;
+    use hyper::{Client, StatusCode};
+    use std::net::TcpListener;
+    use std::thread;
+
+    #[test]
+    fn retry_after_is_seconds() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("30"));
+        assert_eq!(Some(Duration::from_secs(30)), retry_after(&headers));
+    }
+
+    #[test]
+    fn retry_after_is_honored_in_full() {
+        let policy = RetryPolicy::default();
+        let rate_limited = |secs| Error::RateLimited {
+            retry_after: Some(Duration::from_secs(secs)),
+        };
+        assert_eq!(
+            Some(Duration::from_secs(30)),
+            policy.delay_for(&rate_limited(30), 0, false)
+        );
+        assert_eq!(None, policy.delay_for(&rate_limited(31), 0, false));
+    }
+
+    #[test]
+    fn backoff_stays_under_ceiling() {
+        let policy = RetryPolicy::default();
+        for attempt in 0..10 {
+            assert!(policy.backoff(attempt) <= policy.max_delay);
+        }
+        assert!(policy.backoff(0) <= policy.base_delay);
+    }
+
+    #[test]
+    fn bucket_waits_once_drained() {
+        let now = Instant::now();
+        let mut bucket = Bucket::new(Tier::Tier1, now);
+        assert_eq!(Duration::from_secs(0), bucket.reserve(now));
+        assert_eq!(Duration::from_secs(60), bucket.reserve(now));
+    }
+
+    #[test]
+    fn zero_per_minute_is_one() {
+        let now = Instant::now();
+        let mut bucket = Bucket::new(Tier::Custom { per_minute: 0 }, now);
+        assert_eq!(Duration::from_secs(0), bucket.reserve(now));
+        assert_eq!(Duration::from_secs(60), bucket.reserve(now));
+    }
+
+    #[test]
+    fn bucket_honors_block() {
+        let now = Instant::now();
+        let mut bucket = Bucket::new(Tier::Tier4, now);
+        bucket.blocked_until = Some(now + Duration::from_secs(5));
+        assert_eq!(Duration::from_secs(5), bucket.reserve(now));
+    }
+
+    #[tokio::test]
+    async fn only_connect_errors_are_retried() {
+        let policy = RetryPolicy::default();
+
+        // Nothing listens on the port once the listener is dropped.
+        let addr = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let err = Client::new()
+            .get(format!("http://{}", addr).parse().unwrap())
+            .await
+            .unwrap_err();
+        assert!(policy.delay_for(&Error::Transport(err), 0, false).is_some());
+
+        // The request was sent before the connection dropped, so it may have been handled.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            drop(stream);
+        });
+        let err = Client::new()
+            .get(format!("http://{}", addr).parse().unwrap())
+            .await
+            .unwrap_err();
+        server.join().unwrap();
+        assert!(!err.is_connect());
+        assert!(policy
+            .delay_for(&Error::Transport(err), 0, idempotent("chat.postMessage"))
+            .is_none());
+    }
+
+    #[test]
+    fn server_errors_are_only_retried_when_idempotent() {
+        let policy = RetryPolicy::default();
+        let errors = vec![
+            Error::Http {
+                status: StatusCode::BAD_GATEWAY,
+                body: "".to_string(),
+            },
+            Error::Api(ApiErrorCode::InternalError),
+            Error::Api(ApiErrorCode::ServiceUnavailable),
+        ];
+        for err in &errors {
+            assert!(policy
+                .delay_for(err, 0, idempotent("conversations.history"))
+                .is_some());
+            for method in &[
+                "chat.postMessage",
+                "chat.scheduleMessage",
+                "files.completeUploadExternal",
+            ] {
+                assert!(policy.delay_for(err, 0, idempotent(method)).is_none());
+            }
+        }
+        // Rate limits are retried either way, Slack didn't act on the request.
+        assert!(policy
+            .delay_for(&Error::Api(ApiErrorCode::RateLimited), 0, false)
+            .is_some());
+    }
+}
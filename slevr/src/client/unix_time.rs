@@ -0,0 +1,42 @@
+//! Serializes `SystemTime` as whole seconds since the unix epoch, the way the
+//! Web API expects `post_at`, `oldest` and friends.
+use serde::{de::Error as _, ser::Error as _, Deserialize, Deserializer, Serializer};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Fails for times before the epoch, rather than sending Slack a 0 it would reject with
+/// a confusing error.
+pub(crate) fn serialize<S>(time: &SystemTime, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .map_err(|_| S::Error::custom("time is before the unix epoch"))?
+        .as_secs();
+    serializer.serialize_u64(secs)
+}
+
+pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<SystemTime, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let secs = u64::deserialize(deserializer)?;
+    UNIX_EPOCH
+        .checked_add(Duration::from_secs(secs))
+        .ok_or_else(|| D::Error::custom("timestamp out of range"))
+}
+
+pub(crate) mod option {
+    use serde::Serializer;
+    use std::time::SystemTime;
+
+    pub(crate) fn serialize<S>(time: &Option<SystemTime>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match time {
+            Some(time) => super::serialize(time, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+}
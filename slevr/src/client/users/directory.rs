@@ -0,0 +1,157 @@
+use super::info::UsersInfo;
+use super::list::UsersList;
+use crate::events::User;
+use crate::{InnerEvent, Result, SlackApiClient, UserId};
+use futures::TryStreamExt;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+struct Entry {
+    user: Arc<User>,
+    fetched: Instant,
+}
+
+/// Remembers users it's looked up for `ttl`, so resolving names and timezones doesn't
+/// cost a users.info call every time. Feed it events with `observe` to pick up profile
+/// changes as they happen.
+pub struct UserDirectory {
+    ttl: Duration,
+    entries: Mutex<HashMap<UserId, Entry>>,
+}
+
+impl UserDirectory {
+    pub fn new(ttl: Duration) -> Self {
+        UserDirectory {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The user if they're cached and haven't expired.
+    pub fn cached(&self, user: &UserId) -> Option<Arc<User>> {
+        let entries = self.entries.lock().unwrap();
+        entries
+            .get(user)
+            .filter(|entry| entry.fetched.elapsed() < self.ttl)
+            .map(|entry| entry.user.clone())
+    }
+
+    /// The user from the cache, or from users.info if they aren't cached or have expired.
+    pub async fn get(&self, client: &SlackApiClient, user: &UserId) -> Result<Arc<User>> {
+        if let Some(user) = self.cached(user) {
+            return Ok(user);
+        }
+        // Like CachedSecrets, the lock isn't held while fetching.
+        let response = client
+            .users_info(UsersInfo {
+                user: user.clone(),
+                ..Default::default()
+            })
+            .await?;
+        Ok(self.insert(response.user))
+    }
+
+    /// The name to show for a user, falling back to their ID if they can't be looked up.
+    pub async fn display_name(&self, client: &SlackApiClient, user: &UserId) -> String {
+        match self.get(client, user).await {
+            Ok(user) => user.display_name().to_string(),
+            Err(_) => user.to_string(),
+        }
+    }
+
+    /// Fills the cache with everyone in the workspace, a page of users.list at a time.
+    /// Returns how many users were cached.
+    pub async fn load_all(&self, client: &SlackApiClient) -> Result<usize> {
+        client
+            .paginate(UsersList::default())
+            .page_size(200)
+            .items()
+            .try_fold(0, |count, user| {
+                self.insert(user);
+                futures::future::ready(Ok(count + 1))
+            })
+            .await
+    }
+
+    /// Drops anyone named by a user_change or team_join event, so the next lookup sees
+    /// their new profile. Other events are ignored.
+    pub fn observe(&self, event: &InnerEvent) {
+        match event {
+            InnerEvent::UserChange { user } | InnerEvent::TeamJoin { user } => {
+                self.invalidate(&user.id);
+            }
+            _ => {}
+        }
+    }
+
+    /// Forgets a user, so the next `get` goes to Slack.
+    pub fn invalidate(&self, user: &UserId) {
+        self.entries.lock().unwrap().remove(user);
+    }
+
+    fn insert(&self, user: User) -> Arc<User> {
+        let user = Arc::new(user);
+        self.entries.lock().unwrap().insert(
+            user.id.clone(),
+            Entry {
+                user: user.clone(),
+                fetched: Instant::now(),
+            },
+        );
+        user
+    }
+}
+
+/// An hour, which is plenty for names and timezones that rarely change, and user_change
+/// covers the rest.
+impl Default for UserDirectory {
+    fn default() -> Self {
+        UserDirectory::new(Duration::from_secs(60 * 60))
+    }
+}
+
+impl fmt::Debug for UserDirectory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UserDirectory")
+            .field("ttl", &self.ttl)
+            .field("users", &self.entries.lock().unwrap().len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(id: &str, name: &str) -> User {
+        serde_json::from_str(&format!(
+            r#"{{"id": "{}", "name": "{}", "profile": {{"display_name": ""}}}}"#,
+            id, name
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn directory_caches_until_invalidated() {
+        let directory = UserDirectory::default();
+        let id = UserId::from("W012A3CDE");
+        assert!(directory.cached(&id).is_none());
+
+        directory.insert(user("W012A3CDE", "spengler"));
+        assert_eq!("spengler", directory.cached(&id).unwrap().display_name());
+
+        directory.observe(&InnerEvent::UserChange {
+            user: user("W012A3CDE", "egon"),
+        });
+        assert!(directory.cached(&id).is_none());
+    }
+
+    #[test]
+    fn directory_entries_expire() {
+        let directory = UserDirectory::new(Duration::from_secs(0));
+        directory.insert(user("W012A3CDE", "spengler"));
+        assert!(directory.cached(&UserId::from("W012A3CDE")).is_none());
+    }
+}
@@ -0,0 +1,35 @@
+use crate::{Result, SlackApiClient, UserId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Default)]
+pub struct UsersGetPresence {
+    /// Defaults to the calling user
+    pub user: Option<UserId>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Presence {
+    Active,
+    Away,
+}
+
+/// Everything but presence is only sent when asking about the calling user.
+#[derive(Deserialize, Debug)]
+pub struct UsersGetPresenceResponse {
+    pub presence: Presence,
+    pub online: Option<bool>,
+    pub auto_away: Option<bool>,
+    pub manual_away: Option<bool>,
+    pub connection_count: Option<u32>,
+    pub last_activity: Option<u64>,
+}
+
+impl SlackApiClient {
+    pub async fn users_get_presence(
+        &self,
+        presence: UsersGetPresence,
+    ) -> Result<UsersGetPresenceResponse> {
+        self.post_form("users.getPresence", &presence).await
+    }
+}
@@ -0,0 +1,21 @@
+use crate::events::User;
+use crate::{Result, SlackApiClient, UserId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Default)]
+pub struct UsersInfo {
+    pub user: UserId,
+    /// Set this to true to receive the locale for this user. Defaults to false
+    pub include_locale: Option<bool>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UsersInfoResponse {
+    pub user: User,
+}
+
+impl SlackApiClient {
+    pub async fn users_info(&self, info: UsersInfo) -> Result<UsersInfoResponse> {
+        self.post_form("users.info", &info).await
+    }
+}
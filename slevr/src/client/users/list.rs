@@ -0,0 +1,32 @@
+use crate::events::User;
+use crate::{ResponseMetadata, Result, SlackApiClient, TeamId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct UsersList {
+    /// Paginate through collections of data by setting the cursor parameter to a
+    /// next_cursor attribute returned by a previous request's response_metadata.
+    pub cursor: Option<String>,
+    /// Set this to true to receive the locale for users. Defaults to false
+    pub include_locale: Option<bool>,
+    /// The maximum number of items to return. Slack recommends no more than 200.
+    pub limit: Option<u32>,
+    /// Required for org-wide apps
+    pub team_id: Option<TeamId>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UsersListResponse {
+    /// Includes deleted users and bots
+    pub members: Vec<User>,
+    pub cache_ts: Option<u64>,
+    pub response_metadata: Option<ResponseMetadata>,
+}
+
+impl SlackApiClient {
+    pub async fn users_list(&self, list: UsersList) -> Result<UsersListResponse> {
+        self.post_form("users.list", &list).await
+    }
+}
+
+paginated!(UsersList => users_list, members: User);
@@ -0,0 +1,23 @@
+use crate::events::User;
+use crate::{Result, SlackApiClient};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Default)]
+pub struct UsersLookupByEmail {
+    pub email: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UsersLookupByEmailResponse {
+    pub user: User,
+}
+
+impl SlackApiClient {
+    /// Finds a user by their email address, failing with users_not_found if there's no one.
+    pub async fn users_lookup_by_email(
+        &self,
+        lookup: UsersLookupByEmail,
+    ) -> Result<UsersLookupByEmailResponse> {
+        self.post_form("users.lookupByEmail", &lookup).await
+    }
+}
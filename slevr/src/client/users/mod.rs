@@ -0,0 +1,11 @@
+//! Looking up workspace members. The users themselves are the same `User` that team_join
+//! and user_change events carry.
+//! More info - https://api.slack.com/types/user
+mod directory;
+pub mod get_presence;
+pub mod info;
+pub mod list;
+pub mod lookup_by_email;
+pub mod profile_get;
+
+pub use directory::UserDirectory;
@@ -0,0 +1,25 @@
+use crate::events::UserProfile;
+use crate::{Result, SlackApiClient, UserId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Default)]
+pub struct UsersProfileGet {
+    /// Defaults to the calling user
+    pub user: Option<UserId>,
+    /// Include labels for each ID in custom profile fields
+    pub include_labels: Option<bool>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UsersProfileGetResponse {
+    pub profile: UserProfile,
+}
+
+impl SlackApiClient {
+    pub async fn users_profile_get(
+        &self,
+        profile: UsersProfileGet,
+    ) -> Result<UsersProfileGetResponse> {
+        self.post_form("users.profile.get", &profile).await
+    }
+}
@@ -0,0 +1,201 @@
+//! Modals and App Home tabs.
+//! More info - https://api.slack.com/surfaces/modals
+use crate::blocks::{Block, Text};
+use crate::interactions::ViewPayload;
+use serde::{Deserialize, Serialize};
+use state::ValidationErrors;
+
+pub mod open;
+pub mod publish;
+pub mod push;
+pub mod state;
+pub mod update;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ViewType {
+    Modal,
+    Home,
+}
+
+/// A view to open, push, update or publish.
+/// More info - https://api.slack.com/reference/surfaces/views
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct View {
+    pub r#type: ViewType,
+    /// Required for modals, at most 24 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<Text>,
+    /// Up to 100 blocks.
+    pub blocks: Vec<Block>,
+    /// Label for the modal's close button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close: Option<Text>,
+    /// Label for the modal's submit button, required when there are input blocks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submit: Option<Text>,
+    /// Sent back with every interaction and submission, up to 3000 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_metadata: Option<String>,
+    /// Sent back with every interaction and submission.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_id: Option<String>,
+    /// Close every view in the stack when this one is closed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clear_on_close: Option<bool>,
+    /// Send a view_closed payload when the modal is closed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_on_close: Option<bool>,
+    /// Your own id for the view, unique per workspace. Can be used instead of the view id
+    /// in views.update.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submit_disabled: Option<bool>,
+}
+
+impl View {
+    pub fn modal(title: impl Into<String>, blocks: Vec<Block>) -> Self {
+        View {
+            r#type: ViewType::Modal,
+            title: Some(Text::plain(title)),
+            ..View::home(blocks)
+        }
+    }
+
+    /// The App Home tab, see views.publish.
+    pub fn home(blocks: Vec<Block>) -> Self {
+        View {
+            r#type: ViewType::Home,
+            title: None,
+            blocks,
+            close: None,
+            submit: None,
+            private_metadata: None,
+            callback_id: None,
+            clear_on_close: None,
+            notify_on_close: None,
+            external_id: None,
+            submit_disabled: None,
+        }
+    }
+
+    pub fn submit(mut self, label: impl Into<String>) -> Self {
+        self.submit = Some(Text::plain(label));
+        self
+    }
+
+    pub fn close(mut self, label: impl Into<String>) -> Self {
+        self.close = Some(Text::plain(label));
+        self
+    }
+
+    pub fn private_metadata(mut self, private_metadata: impl Into<String>) -> Self {
+        self.private_metadata = Some(private_metadata.into());
+        self
+    }
+
+    pub fn callback_id(mut self, callback_id: impl Into<String>) -> Self {
+        self.callback_id = Some(callback_id.into());
+        self
+    }
+
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+
+    pub fn clear_on_close(mut self, clear_on_close: bool) -> Self {
+        self.clear_on_close = Some(clear_on_close);
+        self
+    }
+
+    pub fn notify_on_close(mut self, notify_on_close: bool) -> Self {
+        self.notify_on_close = Some(notify_on_close);
+        self
+    }
+}
+
+/// What every views method returns: the view as Slack now has it, with its id and hash.
+#[derive(Deserialize, Debug)]
+pub struct ViewResponse {
+    pub view: ViewPayload,
+}
+
+/// The body to answer a view_submission with, instead of the empty 200 that closes the
+/// modal. It has to go back in the HTTP response, within 3 seconds.
+/// More info - https://api.slack.com/surfaces/modals/using#handling_submissions
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "response_action", rename_all = "snake_case")]
+pub enum ResponseAction {
+    /// Keep the modal open and show these errors next to their inputs.
+    Errors { errors: ValidationErrors },
+    /// Replace the submitted view.
+    Update { view: View },
+    /// Push a new view on top of the submitted one.
+    Push { view: View },
+    /// Close every view in the stack.
+    Clear,
+}
+
+impl From<ValidationErrors> for ResponseAction {
+    fn from(errors: ValidationErrors) -> Self {
+        ResponseAction::Errors { errors }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blocks::{BlocksBuilder, PlainTextInput};
+    use serde_json::json;
+
+    #[test]
+    fn view_works() {
+        let view = View::modal(
+            "Feedback",
+            BlocksBuilder::new()
+                .input("Your feedback", PlainTextInput::new("feedback"))
+                .build(),
+        )
+        .submit("Send")
+        .callback_id("feedback")
+        .private_metadata("C024BE91L")
+        .notify_on_close(true);
+        let json = serde_json::to_value(&view).unwrap();
+        assert_eq!("modal", json["type"]);
+        assert_eq!(
+            json!({"type": "plain_text", "text": "Feedback"}),
+            json["title"]
+        );
+        assert_eq!("Send", json["submit"]["text"]);
+        assert_eq!("feedback", json["callback_id"]);
+        assert_eq!("C024BE91L", json["private_metadata"]);
+        assert_eq!(true, json["notify_on_close"]);
+        assert_eq!("input", json["blocks"][0]["type"]);
+        assert!(json.get("close").is_none());
+
+        let home = serde_json::to_value(View::home(vec![])).unwrap();
+        assert_eq!(json!({"type": "home", "blocks": []}), home);
+    }
+
+    #[test]
+    fn response_action_works() {
+        let mut errors = ValidationErrors::new();
+        errors.add(
+            "ticket-due-date",
+            "You may not select a due date in the past",
+        );
+        assert_eq!(
+            json!({
+                "response_action": "errors",
+                "errors": { "ticket-due-date": "You may not select a due date in the past" }
+            }),
+            serde_json::to_value(ResponseAction::from(errors)).unwrap()
+        );
+        assert_eq!(
+            json!({"response_action": "clear"}),
+            serde_json::to_value(ResponseAction::Clear).unwrap()
+        );
+    }
+}
@@ -0,0 +1,17 @@
+use super::{View, ViewResponse};
+use crate::{Result, SlackApiClient};
+use serde::Serialize;
+
+#[derive(Serialize, Debug)]
+pub struct ViewsOpen {
+    /// From a slash command or interaction, only valid for 3 seconds.
+    pub trigger_id: String,
+    pub view: View,
+}
+
+impl SlackApiClient {
+    /// Opens a modal.
+    pub async fn views_open(&self, open: ViewsOpen) -> Result<ViewResponse> {
+        self.post("views.open", &open).await
+    }
+}
@@ -0,0 +1,21 @@
+use super::{View, ViewResponse};
+use crate::{Result, SlackApiClient, UserId};
+use serde::Serialize;
+
+#[derive(Serialize, Debug)]
+pub struct ViewsPublish {
+    /// Whose App Home to publish to.
+    pub user_id: UserId,
+    /// Has to be a home view.
+    pub view: View,
+    /// The hash from the last copy of the view you saw, to avoid overwriting a newer one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+}
+
+impl SlackApiClient {
+    /// Creates or replaces a user's App Home.
+    pub async fn views_publish(&self, publish: ViewsPublish) -> Result<ViewResponse> {
+        self.post("views.publish", &publish).await
+    }
+}
@@ -0,0 +1,17 @@
+use super::{View, ViewResponse};
+use crate::{Result, SlackApiClient};
+use serde::Serialize;
+
+#[derive(Serialize, Debug)]
+pub struct ViewsPush {
+    /// From an interaction in the open modal, only valid for 3 seconds.
+    pub trigger_id: String,
+    pub view: View,
+}
+
+impl SlackApiClient {
+    /// Pushes a new view onto the open modal's stack, which holds up to 3 views.
+    pub async fn views_push(&self, push: ViewsPush) -> Result<ViewResponse> {
+        self.post("views.push", &push).await
+    }
+}
@@ -0,0 +1,577 @@
+//! Reading a submitted view's input values into your own types.
+//!
+//! ```
+//! use slevr::interactions::ViewState;
+//! use slevr::views::state::{Date, FromViewState, StateReader, ValidationErrors};
+//! use slevr::UserId;
+//!
+//! struct Booking {
+//!     who: UserId,
+//!     when: Date,
+//!     nights: i64,
+//!     notes: Option<String>,
+//! }
+//!
+//! impl FromViewState for Booking {
+//!     fn from_view_state(state: &ViewState) -> Result<Self, ValidationErrors> {
+//!         let mut reader = StateReader::new(state);
+//!         let booking = Booking {
+//!             who: reader.get("who", "user"),
+//!             when: reader.get("when", "date"),
+//!             nights: reader.get("nights", "count"),
+//!             notes: reader.get("notes", "text"),
+//!         };
+//!         if booking.nights < 1 {
+//!             reader.error("nights", "Stay at least one night");
+//!         }
+//!         reader.finish(booking)
+//!     }
+//! }
+//! ```
+use crate::blocks::OptionObject;
+use crate::interactions::ViewState;
+use crate::{ChannelId, UserId};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// The value of one input element, by element type.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StateValue {
+    PlainTextInput {
+        value: Option<String>,
+    },
+    Datepicker {
+        selected_date: Option<String>,
+    },
+    StaticSelect {
+        selected_option: Option<OptionObject>,
+    },
+    ExternalSelect {
+        selected_option: Option<OptionObject>,
+    },
+    RadioButtons {
+        selected_option: Option<OptionObject>,
+    },
+    MultiStaticSelect {
+        #[serde(default)]
+        selected_options: Vec<OptionObject>,
+    },
+    MultiExternalSelect {
+        #[serde(default)]
+        selected_options: Vec<OptionObject>,
+    },
+    Checkboxes {
+        #[serde(default)]
+        selected_options: Vec<OptionObject>,
+    },
+    UsersSelect {
+        selected_user: Option<UserId>,
+    },
+    MultiUsersSelect {
+        #[serde(default)]
+        selected_users: Vec<UserId>,
+    },
+    ConversationsSelect {
+        selected_conversation: Option<ChannelId>,
+    },
+    MultiConversationsSelect {
+        #[serde(default)]
+        selected_conversations: Vec<ChannelId>,
+    },
+    ChannelsSelect {
+        selected_channel: Option<ChannelId>,
+    },
+    MultiChannelsSelect {
+        #[serde(default)]
+        selected_channels: Vec<ChannelId>,
+    },
+    /// An element we don't know how to read.
+    #[serde(other)]
+    Unknown,
+}
+
+impl StateValue {
+    /// The single value of a text input, single select, radio group or datepicker.
+    fn text(&self) -> Option<&str> {
+        match self {
+            StateValue::PlainTextInput { value } => value.as_deref(),
+            StateValue::Datepicker { selected_date } => selected_date.as_deref(),
+            StateValue::StaticSelect { selected_option }
+            | StateValue::ExternalSelect { selected_option }
+            | StateValue::RadioButtons { selected_option } => {
+                selected_option.as_ref().map(|option| &option.value[..])
+            }
+            _ => None,
+        }
+    }
+
+    /// The values of a multi-select or checkbox group.
+    fn options(&self) -> Option<&[OptionObject]> {
+        match self {
+            StateValue::MultiStaticSelect { selected_options }
+            | StateValue::MultiExternalSelect { selected_options }
+            | StateValue::Checkboxes { selected_options } => Some(selected_options),
+            _ => None,
+        }
+    }
+}
+
+/// Why a field couldn't be read.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldError {
+    /// Nothing was entered or selected.
+    Missing,
+    /// Something was entered, but it wasn't usable. The message is shown to the user.
+    Invalid(String),
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldError::Missing => write!(f, "Please fill this in"),
+            FieldError::Invalid(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+fn wrong_element() -> FieldError {
+    FieldError::Invalid("This field can't be read as that type".to_string())
+}
+
+/// A type an input element's value can be read as. Implemented for `String`, `i64`, `bool`,
+/// [`Date`], `UserId`, `ChannelId`, `Vec`s of those for multi-selects and checkboxes, and
+/// `Option`s of everything for optional inputs.
+pub trait FromStateValue: Sized {
+    fn from_state_value(value: &StateValue) -> Result<Self, FieldError>;
+
+    /// What an input that was left out of the state entirely reads as.
+    fn missing() -> Result<Self, FieldError> {
+        Err(FieldError::Missing)
+    }
+}
+
+impl FromStateValue for String {
+    fn from_state_value(value: &StateValue) -> Result<Self, FieldError> {
+        match value {
+            StateValue::PlainTextInput { .. }
+            | StateValue::Datepicker { .. }
+            | StateValue::StaticSelect { .. }
+            | StateValue::ExternalSelect { .. }
+            | StateValue::RadioButtons { .. } => value
+                .text()
+                .filter(|text| !text.is_empty())
+                .map(str::to_string)
+                .ok_or(FieldError::Missing),
+            _ => Err(wrong_element()),
+        }
+    }
+}
+
+impl FromStateValue for i64 {
+    fn from_state_value(value: &StateValue) -> Result<Self, FieldError> {
+        String::from_state_value(value)?
+            .trim()
+            .parse()
+            .map_err(|_| FieldError::Invalid("Please enter a whole number".to_string()))
+    }
+}
+
+/// A checkbox group is true when anything in it is ticked.
+impl FromStateValue for bool {
+    fn from_state_value(value: &StateValue) -> Result<Self, FieldError> {
+        match value {
+            StateValue::Checkboxes { selected_options } => Ok(!selected_options.is_empty()),
+            _ => Err(wrong_element()),
+        }
+    }
+
+    fn missing() -> Result<Self, FieldError> {
+        Ok(false)
+    }
+}
+
+impl FromStateValue for Date {
+    fn from_state_value(value: &StateValue) -> Result<Self, FieldError> {
+        String::from_state_value(value)?
+            .parse()
+            .map_err(|_| FieldError::Invalid("Please enter a date like 2020-03-31".to_string()))
+    }
+}
+
+impl FromStateValue for UserId {
+    fn from_state_value(value: &StateValue) -> Result<Self, FieldError> {
+        match value {
+            StateValue::UsersSelect { selected_user } => {
+                selected_user.clone().ok_or(FieldError::Missing)
+            }
+            _ => Err(wrong_element()),
+        }
+    }
+}
+
+impl FromStateValue for ChannelId {
+    fn from_state_value(value: &StateValue) -> Result<Self, FieldError> {
+        match value {
+            StateValue::ConversationsSelect {
+                selected_conversation: selected,
+            }
+            | StateValue::ChannelsSelect {
+                selected_channel: selected,
+            } => selected.clone().ok_or(FieldError::Missing),
+            _ => Err(wrong_element()),
+        }
+    }
+}
+
+/// The selected option values.
+impl FromStateValue for Vec<String> {
+    fn from_state_value(value: &StateValue) -> Result<Self, FieldError> {
+        let options = value.options().ok_or_else(wrong_element)?;
+        Ok(options.iter().map(|option| option.value.clone()).collect())
+    }
+
+    fn missing() -> Result<Self, FieldError> {
+        Ok(vec![])
+    }
+}
+
+impl FromStateValue for Vec<UserId> {
+    fn from_state_value(value: &StateValue) -> Result<Self, FieldError> {
+        match value {
+            StateValue::MultiUsersSelect { selected_users } => Ok(selected_users.clone()),
+            _ => Err(wrong_element()),
+        }
+    }
+
+    fn missing() -> Result<Self, FieldError> {
+        Ok(vec![])
+    }
+}
+
+impl FromStateValue for Vec<ChannelId> {
+    fn from_state_value(value: &StateValue) -> Result<Self, FieldError> {
+        match value {
+            StateValue::MultiConversationsSelect {
+                selected_conversations: selected,
+            }
+            | StateValue::MultiChannelsSelect {
+                selected_channels: selected,
+            } => Ok(selected.clone()),
+            _ => Err(wrong_element()),
+        }
+    }
+
+    fn missing() -> Result<Self, FieldError> {
+        Ok(vec![])
+    }
+}
+
+impl<T: FromStateValue> FromStateValue for Option<T> {
+    fn from_state_value(value: &StateValue) -> Result<Self, FieldError> {
+        match T::from_state_value(value) {
+            Ok(value) => Ok(Some(value)),
+            Err(FieldError::Missing) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn missing() -> Result<Self, FieldError> {
+        Ok(None)
+    }
+}
+
+/// A datepicker's date.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl FromStr for Date {
+    type Err = FieldError;
+
+    /// Parses the YYYY-MM-DD format datepickers use.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || FieldError::Invalid(format!("{:?} isn't a YYYY-MM-DD date", s));
+        let mut parts = s.splitn(3, '-');
+        let mut part = || parts.next().ok_or_else(invalid);
+        let (year, month, day) = (part()?, part()?, part()?);
+        let date = Date {
+            year: year.parse().map_err(|_| invalid())?,
+            month: month.parse().map_err(|_| invalid())?,
+            day: day.parse().map_err(|_| invalid())?,
+        };
+        if year.len() != 4
+            || !(1..=12).contains(&date.month)
+            || !(1..=date.days_in_month()).contains(&date.day)
+        {
+            return Err(invalid());
+        }
+        Ok(date)
+    }
+}
+
+impl Date {
+    fn days_in_month(self) -> u8 {
+        let leap = match (self.year % 4, self.year % 100, self.year % 400) {
+            (_, _, 0) => true,
+            (_, 0, _) => false,
+            (0, _, _) => true,
+            _ => false,
+        };
+        match self.month {
+            4 | 6 | 9 | 11 => 30,
+            2 if leap => 29,
+            2 => 28,
+            _ => 31,
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl ViewState {
+    /// Reads the input with `action_id` in the block `block_id`.
+    pub fn get<T: FromStateValue>(&self, block_id: &str, action_id: &str) -> Result<T, FieldError> {
+        let value = match self
+            .values
+            .get(block_id)
+            .and_then(|block| block.get(action_id))
+        {
+            Some(value) => value,
+            None => return T::missing(),
+        };
+        let value = StateValue::deserialize(value).map_err(|_| wrong_element())?;
+        T::from_state_value(&value)
+    }
+
+    /// Reads the whole state into `T`.
+    pub fn parse<T: FromViewState>(&self) -> Result<T, ValidationErrors> {
+        T::from_view_state(self)
+    }
+}
+
+/// Error messages keyed by the block_id of the input they belong to, which is what Slack
+/// wants back to show them next to the inputs.
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct ValidationErrors(BTreeMap<String, String>);
+
+impl ValidationErrors {
+    pub fn new() -> Self {
+        ValidationErrors::default()
+    }
+
+    /// Only the first error for each block is kept, Slack can only show one.
+    pub fn add(&mut self, block_id: impl Into<String>, message: impl Into<String>) {
+        self.0
+            .entry(block_id.into())
+            .or_insert_with(|| message.into());
+    }
+
+    pub fn get(&self, block_id: &str) -> Option<&str> {
+        self.0.get(block_id).map(|message| &message[..])
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let errors = self
+            .0
+            .iter()
+            .map(|(block_id, message)| format!("{}: {}", block_id, message))
+            .collect::<Vec<_>>();
+        write!(f, "{}", errors.join(", "))
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+/// Implement this to read a submitted view into your own type, usually with a
+/// [`StateReader`].
+pub trait FromViewState: Sized {
+    fn from_view_state(state: &ViewState) -> Result<Self, ValidationErrors>;
+}
+
+/// Reads fields one at a time, keeping track of every error so they can all be shown at
+/// once rather than one per submission.
+#[derive(Debug)]
+pub struct StateReader<'a> {
+    state: &'a ViewState,
+    errors: ValidationErrors,
+}
+
+impl<'a> StateReader<'a> {
+    pub fn new(state: &'a ViewState) -> Self {
+        StateReader {
+            state,
+            errors: ValidationErrors::new(),
+        }
+    }
+
+    /// Reads a field, or remembers the error against its block and hands back a default
+    /// that [`StateReader::finish`] will never let out.
+    pub fn get<T: FromStateValue + Default>(&mut self, block_id: &str, action_id: &str) -> T {
+        match self.state.get(block_id, action_id) {
+            Ok(value) => value,
+            Err(err) => {
+                self.errors.add(block_id, err.to_string());
+                T::default()
+            }
+        }
+    }
+
+    /// The raw JSON, for elements the typed readers don't cover.
+    pub fn raw(&self, block_id: &str, action_id: &str) -> Option<&'a Value> {
+        self.state.values.get(block_id)?.get(action_id)
+    }
+
+    /// Adds an error of your own, for checks beyond what the types cover.
+    pub fn error(&mut self, block_id: &str, message: impl Into<String>) {
+        self.errors.add(block_id, message);
+    }
+
+    /// `value` if every field read cleanly and no errors were added.
+    pub fn finish<T>(self, value: T) -> Result<T, ValidationErrors> {
+        if self.errors.is_empty() {
+            Ok(value)
+        } else {
+            Err(self.errors)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn state(values: Value) -> ViewState {
+        serde_json::from_value(json!({ "values": values })).unwrap()
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Booking {
+        who: UserId,
+        rooms: Vec<String>,
+        when: Date,
+        nights: i64,
+        notes: Option<String>,
+        breakfast: bool,
+        channel: Option<ChannelId>,
+    }
+
+    impl FromViewState for Booking {
+        fn from_view_state(state: &ViewState) -> Result<Self, ValidationErrors> {
+            let mut reader = StateReader::new(state);
+            let booking = Booking {
+                who: reader.get("who", "user"),
+                rooms: reader.get("rooms", "pick"),
+                when: reader.get("when", "date"),
+                nights: reader.get("nights", "count"),
+                notes: reader.get("notes", "text"),
+                breakfast: reader.get("extras", "breakfast"),
+                channel: reader.get("channel", "pick"),
+            };
+            if booking.nights < 1 {
+                reader.error("nights", "Stay at least one night");
+            }
+            reader.finish(booking)
+        }
+    }
+
+    fn option(value: &str) -> Value {
+        json!({ "text": { "type": "plain_text", "text": value }, "value": value })
+    }
+
+    #[test]
+    fn from_view_state_works() {
+        let state = state(json!({
+            "who": { "user": { "type": "users_select", "selected_user": "U061F7AUR" } },
+            "rooms": { "pick": {
+                "type": "multi_static_select",
+                "selected_options": [option("blue"), option("green")]
+            } },
+            "when": { "date": { "type": "datepicker", "selected_date": "2020-03-31" } },
+            "nights": { "count": { "type": "plain_text_input", "value": " 3 " } },
+            "notes": { "text": { "type": "plain_text_input", "value": null } },
+            "extras": { "breakfast": { "type": "checkboxes", "selected_options": [option("yes")] } },
+            "channel": { "pick": { "type": "conversations_select", "selected_conversation": "C012AB3CD" } }
+        }));
+        assert_eq!(
+            Ok(Booking {
+                who: "U061F7AUR".into(),
+                rooms: vec!["blue".to_string(), "green".to_string()],
+                when: Date {
+                    year: 2020,
+                    month: 3,
+                    day: 31
+                },
+                nights: 3,
+                notes: None,
+                breakfast: true,
+                channel: Some("C012AB3CD".into()),
+            }),
+            state.parse::<Booking>()
+        );
+    }
+
+    #[test]
+    fn errors_are_keyed_by_block() {
+        let booking = |date: &str| {
+            state(json!({
+                "who": { "user": { "type": "users_select", "selected_user": null } },
+                "rooms": { "pick": { "type": "plain_text_input", "value": "blue" } },
+                "when": { "date": { "type": "datepicker", "selected_date": date } },
+                "nights": { "count": { "type": "plain_text_input", "value": "three" } }
+            }))
+            .parse::<Booking>()
+            .unwrap_err()
+        };
+        // Days are checked against the month, leap years included.
+        for date in &["2020-13-01", "2020-02-31", "2021-04-31", "2021-02-29"] {
+            assert_eq!(
+                Some("Please enter a date like 2020-03-31"),
+                booking(date).get("when"),
+                "{}",
+                date
+            );
+        }
+        assert_eq!(None, booking("2020-02-29").get("when"));
+
+        let errors = booking("2020-13-01");
+        assert_eq!(Some("Please fill this in"), errors.get("who"));
+        assert_eq!(
+            Some("This field can't be read as that type"),
+            errors.get("rooms")
+        );
+        assert_eq!(
+            Some("Please enter a date like 2020-03-31"),
+            errors.get("when")
+        );
+        // The type error wins over the check that ran on the default.
+        assert_eq!(Some("Please enter a whole number"), errors.get("nights"));
+        assert_eq!(None, errors.get("notes"));
+        assert_eq!(
+            json!({
+                "nights": "Please enter a whole number",
+                "rooms": "This field can't be read as that type",
+                "when": "Please enter a date like 2020-03-31",
+                "who": "Please fill this in"
+            }),
+            serde_json::to_value(&errors).unwrap()
+        );
+    }
+}
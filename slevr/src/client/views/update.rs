@@ -0,0 +1,49 @@
+use super::{View, ViewResponse};
+use crate::{Result, SlackApiClient};
+use serde::Serialize;
+
+/// Either `view_id` or `external_id` is required.
+#[derive(Serialize, Debug)]
+pub struct ViewsUpdate {
+    pub view: View,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub view_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    /// The hash from the last copy of the view you saw. If the view has changed since,
+    /// the update fails with `hash_conflict` instead of overwriting it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+}
+
+impl ViewsUpdate {
+    pub fn by_id(view_id: impl Into<String>, view: View) -> Self {
+        ViewsUpdate {
+            view,
+            view_id: Some(view_id.into()),
+            external_id: None,
+            hash: None,
+        }
+    }
+
+    pub fn by_external_id(external_id: impl Into<String>, view: View) -> Self {
+        ViewsUpdate {
+            view,
+            view_id: None,
+            external_id: Some(external_id.into()),
+            hash: None,
+        }
+    }
+
+    pub fn hash(mut self, hash: impl Into<String>) -> Self {
+        self.hash = Some(hash.into());
+        self
+    }
+}
+
+impl SlackApiClient {
+    /// Replaces a modal that's already open, whether or not it's on top of the stack.
+    pub async fn views_update(&self, update: ViewsUpdate) -> Result<ViewResponse> {
+        self.post("views.update", &update).await
+    }
+}
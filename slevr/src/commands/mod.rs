@@ -0,0 +1,5 @@
+//! Commands people send to a bot, either as slash commands or as text in a DM.
+pub mod parser;
+mod slash;
+
+pub use slash::{ResponseType, SlashCommand, SlashResponse};
@@ -0,0 +1,510 @@
+//! Declarative text commands, like the ones people DM to a bot.
+//!
+//! ```
+//! use slevr::commands::parser::{Arg, Command, CommandSet, Invocation};
+//!
+//! let commands = CommandSet::new().command(
+//!     Command::new("echo", "Echo every event to you, or stop")
+//!         .alias("e")
+//!         .arg(Arg::choice("mode", &["all", "none"], "all or none")),
+//! );
+//! match commands.parse("echo all").unwrap() {
+//!     Invocation::Run(parsed) => assert_eq!(Some("all"), parsed.str("mode")),
+//!     Invocation::Help(_) => unreachable!(),
+//! }
+//! assert!(commands.parse("echo some").is_err());
+//! ```
+use crate::{ChannelId, UserId};
+use std::collections::HashMap;
+use std::fmt;
+
+const HELP: &str = "help";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArgKind {
+    /// A single word, or a "quoted phrase"
+    Word,
+    Int,
+    /// One of a fixed set of words
+    Choice(Vec<String>),
+    /// An @mention of a user
+    User,
+    /// A #channel link
+    Channel,
+    /// Everything left on the line, as typed
+    Rest,
+}
+
+#[derive(Debug, Clone)]
+pub struct Arg {
+    name: String,
+    kind: ArgKind,
+    description: String,
+    required: bool,
+}
+
+impl Arg {
+    fn new(name: &str, kind: ArgKind, description: &str) -> Self {
+        Arg {
+            name: name.to_string(),
+            kind,
+            description: description.to_string(),
+            required: true,
+        }
+    }
+
+    pub fn word(name: &str, description: &str) -> Self {
+        Arg::new(name, ArgKind::Word, description)
+    }
+
+    pub fn int(name: &str, description: &str) -> Self {
+        Arg::new(name, ArgKind::Int, description)
+    }
+
+    pub fn choice(name: &str, choices: &[&str], description: &str) -> Self {
+        let choices = choices.iter().map(|choice| choice.to_string()).collect();
+        Arg::new(name, ArgKind::Choice(choices), description)
+    }
+
+    pub fn user(name: &str, description: &str) -> Self {
+        Arg::new(name, ArgKind::User, description)
+    }
+
+    pub fn channel(name: &str, description: &str) -> Self {
+        Arg::new(name, ArgKind::Channel, description)
+    }
+
+    /// Has to be the last argument.
+    pub fn rest(name: &str, description: &str) -> Self {
+        Arg::new(name, ArgKind::Rest, description)
+    }
+
+    /// Optional arguments can only be followed by other optional arguments.
+    pub fn optional(mut self) -> Self {
+        self.required = false;
+        self
+    }
+
+    fn usage(&self) -> String {
+        let inner = match &self.kind {
+            ArgKind::Choice(choices) => choices.join("|"),
+            ArgKind::Rest => format!("{}...", self.name),
+            _ => self.name.clone(),
+        };
+        if self.required {
+            format!("<{}>", inner)
+        } else {
+            format!("[{}]", inner)
+        }
+    }
+
+    fn parse(&self, token: &str) -> Option<Value> {
+        match &self.kind {
+            ArgKind::Word | ArgKind::Rest => Some(Value::Text(token.to_string())),
+            ArgKind::Int => token.parse().ok().map(Value::Int),
+            ArgKind::Choice(choices) => choices
+                .iter()
+                .find(|choice| choice.eq_ignore_ascii_case(token))
+                .map(|choice| Value::Text(choice.clone())),
+            ArgKind::User => entity(token, "<@").map(|id| Value::User(id.into())),
+            ArgKind::Channel => entity(token, "<#").map(|id| Value::Channel(id.into())),
+        }
+    }
+
+    fn expected(&self) -> String {
+        match &self.kind {
+            ArgKind::Word | ArgKind::Rest => "some text".to_string(),
+            ArgKind::Int => "a whole number".to_string(),
+            ArgKind::Choice(choices) => format!("one of {}", choices.join(", ")),
+            ArgKind::User => "an @mention".to_string(),
+            ArgKind::Channel => "a #channel".to_string(),
+        }
+    }
+}
+
+/// The id out of Slack's `<@U123|name>` and `<#C123|name>` formatting.
+fn entity<'a>(token: &'a str, prefix: &str) -> Option<&'a str> {
+    let inner = token.strip_prefix(prefix)?.strip_suffix('>')?;
+    let id = inner.split('|').next()?;
+    if id.is_empty() {
+        None
+    } else {
+        Some(id)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Command {
+    name: String,
+    aliases: Vec<String>,
+    description: String,
+    args: Vec<Arg>,
+}
+
+impl Command {
+    pub fn new(name: &str, description: &str) -> Self {
+        Command {
+            name: name.to_string(),
+            aliases: vec![],
+            description: description.to_string(),
+            args: vec![],
+        }
+    }
+
+    pub fn alias(mut self, alias: &str) -> Self {
+        self.aliases.push(alias.to_string());
+        self
+    }
+
+    pub fn arg(mut self, arg: Arg) -> Self {
+        self.args.push(arg);
+        self
+    }
+
+    fn answers_to(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+            || self
+                .aliases
+                .iter()
+                .any(|alias| alias.eq_ignore_ascii_case(name))
+    }
+
+    pub fn usage(&self) -> String {
+        std::iter::once(self.name.clone())
+            .chain(self.args.iter().map(Arg::usage))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn help(&self) -> String {
+        let mut help = format!("`{}` - {}", self.usage(), self.description);
+        if !self.aliases.is_empty() {
+            help.push_str(&format!(" (also `{}`)", self.aliases.join("`, `")));
+        }
+        for arg in &self.args {
+            help.push_str(&format!("\n    `{}` - {}", arg.name, arg.description));
+        }
+        help
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Text(String),
+    Int(i64),
+    User(UserId),
+    Channel(ChannelId),
+}
+
+/// A command that parsed, with its arguments by name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Parsed {
+    /// Always the command's name, even when it was called by an alias.
+    pub command: String,
+    pub args: HashMap<String, Value>,
+}
+
+impl Parsed {
+    pub fn str(&self, name: &str) -> Option<&str> {
+        match self.args.get(name)? {
+            Value::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    pub fn int(&self, name: &str) -> Option<i64> {
+        match self.args.get(name)? {
+            Value::Int(int) => Some(*int),
+            _ => None,
+        }
+    }
+
+    pub fn user(&self, name: &str) -> Option<&UserId> {
+        match self.args.get(name)? {
+            Value::User(user) => Some(user),
+            _ => None,
+        }
+    }
+
+    pub fn channel(&self, name: &str) -> Option<&ChannelId> {
+        match self.args.get(name)? {
+            Value::Channel(channel) => Some(channel),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Invocation {
+    Run(Parsed),
+    /// `help`, or `help <command>`
+    Help(Option<String>),
+}
+
+/// Why some text wasn't a command. The Display output is meant to be sent straight back
+/// to whoever typed it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    Empty,
+    UnknownCommand(String),
+    MissingArgument {
+        usage: String,
+        arg: String,
+    },
+    InvalidArgument {
+        usage: String,
+        arg: String,
+        value: String,
+        expected: String,
+    },
+    TooManyArguments {
+        usage: String,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "Try `{}` to see what I can do.", HELP),
+            ParseError::UnknownCommand(name) => write!(
+                f,
+                "I don't know how to `{}`. Try `{}` to see what I can do.",
+                name, HELP
+            ),
+            ParseError::MissingArgument { usage, arg } => {
+                write!(f, "Missing `{}`. Usage: `{}`", arg, usage)
+            }
+            ParseError::InvalidArgument {
+                usage,
+                arg,
+                value,
+                expected,
+            } => write!(
+                f,
+                "`{}` isn't a valid `{}`, expected {}. Usage: `{}`",
+                value, arg, expected, usage
+            ),
+            ParseError::TooManyArguments { usage } => {
+                write!(f, "Too many arguments. Usage: `{}`", usage)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Splits on whitespace, keeping "quoted phrases" together. Slack likes to turn straight
+/// quotes into curly ones, so those count too. Each token comes with the byte offset it
+/// started at, so a `Rest` argument can take the rest of the line as typed.
+fn tokenize(text: &str) -> Vec<(usize, String)> {
+    let mut tokens = vec![];
+    let mut current: Option<(usize, String)> = None;
+    let mut quoted = false;
+    for (i, c) in text.char_indices() {
+        match c {
+            '"' | '“' | '”' => {
+                quoted = !quoted;
+                current.get_or_insert_with(|| (i, String::new()));
+            }
+            c if c.is_whitespace() && !quoted => {
+                if let Some(token) = current.take() {
+                    tokens.push(token);
+                }
+            }
+            c => current.get_or_insert_with(|| (i, String::new())).1.push(c),
+        }
+    }
+    if let Some(token) = current {
+        tokens.push(token);
+    }
+    tokens
+}
+
+/// A bot's commands, plus a generated `help`.
+#[derive(Debug, Clone, Default)]
+pub struct CommandSet {
+    commands: Vec<Command>,
+}
+
+impl CommandSet {
+    pub fn new() -> Self {
+        CommandSet::default()
+    }
+
+    pub fn command(mut self, command: Command) -> Self {
+        self.commands.push(command);
+        self
+    }
+
+    fn find(&self, name: &str) -> Option<&Command> {
+        self.commands
+            .iter()
+            .find(|command| command.answers_to(name))
+    }
+
+    pub fn parse(&self, text: &str) -> Result<Invocation, ParseError> {
+        let text = text.trim();
+        let tokens = tokenize(text);
+        let (name, args) = match tokens.split_first() {
+            Some(((_, name), args)) => (name, args),
+            None => return Err(ParseError::Empty),
+        };
+
+        let command = match self.find(name) {
+            Some(command) => command,
+            None if name.eq_ignore_ascii_case(HELP) => {
+                return Ok(Invocation::Help(args.first().map(|(_, arg)| arg.clone())))
+            }
+            None => return Err(ParseError::UnknownCommand(name.clone())),
+        };
+
+        let mut parsed = HashMap::new();
+        let mut tokens = args.iter();
+        for arg in &command.args {
+            let (offset, token) = match tokens.next() {
+                Some(token) => token,
+                None if arg.required => {
+                    return Err(ParseError::MissingArgument {
+                        usage: command.usage(),
+                        arg: arg.name.clone(),
+                    })
+                }
+                None => break,
+            };
+            if arg.kind == ArgKind::Rest {
+                parsed.insert(arg.name.clone(), Value::Text(text[*offset..].to_string()));
+                tokens.by_ref().for_each(drop);
+                break;
+            }
+            let value = arg
+                .parse(token)
+                .ok_or_else(|| ParseError::InvalidArgument {
+                    usage: command.usage(),
+                    arg: arg.name.clone(),
+                    value: token.clone(),
+                    expected: arg.expected(),
+                })?;
+            parsed.insert(arg.name.clone(), value);
+        }
+        if tokens.next().is_some() {
+            return Err(ParseError::TooManyArguments {
+                usage: command.usage(),
+            });
+        }
+
+        Ok(Invocation::Run(Parsed {
+            command: command.name.clone(),
+            args: parsed,
+        }))
+    }
+
+    /// Help for every command, or just the one asked about.
+    pub fn help(&self, command: Option<&str>) -> String {
+        if let Some(name) = command {
+            return match self.find(name) {
+                Some(command) => command.help(),
+                None => ParseError::UnknownCommand(name.to_string()).to_string(),
+            };
+        }
+        let mut help = "Here's what I can do:".to_string();
+        for command in &self.commands {
+            help.push('\n');
+            help.push_str(&command.help());
+        }
+        help.push_str(&format!(
+            "\n`{} [command]` - Show this, or more about one command",
+            HELP
+        ));
+        help
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commands() -> CommandSet {
+        CommandSet::new()
+            .command(
+                Command::new("echo", "Echo every event to you, or stop")
+                    .alias("e")
+                    .arg(Arg::choice("mode", &["all", "none"], "all or none")),
+            )
+            .command(
+                Command::new("remind", "Remind someone about something")
+                    .arg(Arg::user("who", "Who to remind"))
+                    .arg(Arg::int("minutes", "How long from now"))
+                    .arg(Arg::rest("what", "What to remind them about").optional()),
+            )
+    }
+
+    fn run(text: &str) -> Parsed {
+        match commands().parse(text) {
+            Ok(Invocation::Run(parsed)) => parsed,
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_works() {
+        let parsed = run("E  ALL");
+        assert_eq!("echo", parsed.command);
+        assert_eq!(Some("all"), parsed.str("mode"));
+
+        let parsed = run("remind <@U024BE7LH|bob> 10 water the “office plants”");
+        assert_eq!(Some(&UserId::from("U024BE7LH")), parsed.user("who"));
+        assert_eq!(Some(10), parsed.int("minutes"));
+        assert_eq!(Some("water the “office plants”"), parsed.str("what"));
+        assert_eq!(None, run("remind <@U024BE7LH> 10").str("what"));
+    }
+
+    #[test]
+    fn usage_errors() {
+        let commands = commands();
+        assert_eq!(Err(ParseError::Empty), commands.parse("  "));
+        assert_eq!(
+            Err(ParseError::UnknownCommand("dance".to_string())),
+            commands.parse("dance")
+        );
+        assert_eq!(
+            "Missing `mode`. Usage: `echo <all|none>`",
+            commands.parse("echo").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "`some` isn't a valid `mode`, expected one of all, none. Usage: `echo <all|none>`",
+            commands.parse("echo some").unwrap_err().to_string()
+        );
+        assert_eq!(
+            Err(ParseError::TooManyArguments {
+                usage: "echo <all|none>".to_string()
+            }),
+            commands.parse("echo all now")
+        );
+        assert!(commands.parse("remind bob 10").is_err());
+    }
+
+    #[test]
+    fn help_works() {
+        let commands = commands();
+        assert_eq!(Ok(Invocation::Help(None)), commands.parse("help"));
+        assert_eq!(
+            Ok(Invocation::Help(Some("echo".to_string()))),
+            commands.parse("help echo")
+        );
+        assert_eq!(
+            "Here's what I can do:\n\
+             `echo <all|none>` - Echo every event to you, or stop (also `e`)\n    \
+             `mode` - all or none\n\
+             `remind <who> <minutes> [what...]` - Remind someone about something\n    \
+             `who` - Who to remind\n    \
+             `minutes` - How long from now\n    \
+             `what` - What to remind them about\n\
+             `help [command]` - Show this, or more about one command",
+            commands.help(None)
+        );
+        assert_eq!(
+            "`echo <all|none>` - Echo every event to you, or stop (also `e`)\n    `mode` - all or none",
+            commands.help(Some("e"))
+        );
+    }
+}
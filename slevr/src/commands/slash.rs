@@ -0,0 +1,138 @@
+//! Slash commands, which Slack POSTs as a form rather than JSON.
+//! More info - https://api.slack.com/interactivity/slash-commands
+use crate::blocks::Block;
+use crate::form::{self, FormError};
+use crate::{AppId, ChannelId, TeamId, UserId};
+use serde::{Deserialize, Serialize};
+
+/// What Slack sends when someone runs one of your app's slash commands. It's also
+/// Serialize, so it can be passed along as JSON once it's been decoded.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct SlashCommand {
+    /// Deprecated verification token, check the request signature instead.
+    #[serde(default)]
+    pub token: String,
+    /// The command that was typed, e.g. `/weather`
+    pub command: String,
+    /// Everything after the command, with the leading space trimmed. Mentions and links
+    /// keep Slack's `<@U123|name>` formatting.
+    #[serde(default)]
+    pub text: String,
+    pub team_id: TeamId,
+    #[serde(default)]
+    pub team_domain: String,
+    pub enterprise_id: Option<String>,
+    pub enterprise_name: Option<String>,
+    pub channel_id: ChannelId,
+    #[serde(default)]
+    pub channel_name: String,
+    pub user_id: UserId,
+    /// Deprecated, use user_id
+    #[serde(default)]
+    pub user_name: String,
+    pub api_app_id: Option<AppId>,
+    /// Accepts delayed replies for 30 minutes, at most 5 times.
+    pub response_url: String,
+    /// Opens a modal, if used within 3 seconds.
+    pub trigger_id: String,
+}
+
+impl SlashCommand {
+    /// Decodes the body of a slash command request.
+    pub fn from_form(body: &str) -> Result<Self, FormError> {
+        form::from_str(body)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseType {
+    /// Only the person who ran the command sees it.
+    Ephemeral,
+    /// Everyone in the channel sees it, along with the command that was run.
+    InChannel,
+}
+
+/// A reply to a slash command, either sent back as the HTTP response within 3 seconds or
+/// posted to the command's response_url later.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct SlashResponse {
+    pub response_type: ResponseType,
+    /// The message, or the fallback for notifications when there are blocks.
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<Block>>,
+}
+
+impl SlashResponse {
+    pub fn ephemeral(text: impl Into<String>) -> Self {
+        SlashResponse {
+            response_type: ResponseType::Ephemeral,
+            text: text.into(),
+            blocks: None,
+        }
+    }
+
+    pub fn in_channel(text: impl Into<String>) -> Self {
+        SlashResponse {
+            response_type: ResponseType::InChannel,
+            text: text.into(),
+            blocks: None,
+        }
+    }
+
+    pub fn with_blocks(mut self, blocks: Vec<Block>) -> Self {
+        self.blocks = Some(blocks);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The example from Slack's docs.
+    const BODY: &str = "token=gIkuvaNzQIHg97ATvDxqgjtO&team_id=T0001&team_domain=example&enterprise_id=E0001&enterprise_name=Globular%20Construct%20Inc&channel_id=C2147483705&channel_name=test&user_id=U2147483697&user_name=Steve&command=/weather&text=94070&response_url=https://hooks.slack.com/commands/1234/5678&trigger_id=13345224609.738474920.8088930838d88f008e0&api_app_id=A123456";
+
+    #[test]
+    fn slash_command_works() {
+        let command = SlashCommand::from_form(BODY).unwrap();
+        assert_eq!(
+            SlashCommand {
+                token: "gIkuvaNzQIHg97ATvDxqgjtO".to_string(),
+                command: "/weather".to_string(),
+                text: "94070".to_string(),
+                team_id: "T0001".into(),
+                team_domain: "example".to_string(),
+                enterprise_id: Some("E0001".to_string()),
+                enterprise_name: Some("Globular Construct Inc".to_string()),
+                channel_id: "C2147483705".into(),
+                channel_name: "test".to_string(),
+                user_id: "U2147483697".into(),
+                user_name: "Steve".to_string(),
+                api_app_id: Some("A123456".into()),
+                response_url: "https://hooks.slack.com/commands/1234/5678".to_string(),
+                trigger_id: "13345224609.738474920.8088930838d88f008e0".to_string(),
+            },
+            command
+        );
+
+        let json = serde_json::to_string(&command).unwrap();
+        assert_eq!(command, serde_json::from_str(&json).unwrap());
+
+        assert!(SlashCommand::from_form("command=/weather").is_err());
+    }
+
+    #[test]
+    fn slash_response_works() {
+        assert_eq!(
+            r#"{"response_type":"in_channel","text":"It's 80 degrees right now."}"#,
+            serde_json::to_string(&SlashResponse::in_channel("It's 80 degrees right now."))
+                .unwrap()
+        );
+        assert_eq!(
+            r#"{"response_type":"ephemeral","text":"Sorry, that didn't work."}"#,
+            serde_json::to_string(&SlashResponse::ephemeral("Sorry, that didn't work.")).unwrap()
+        );
+    }
+}
@@ -0,0 +1,247 @@
+use hyper::StatusCode;
+use serde::Deserialize;
+use std::fmt;
+use std::time::Duration;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Everything that can go wrong talking to the Slack Web API.
+#[derive(Debug)]
+pub enum Error {
+    /// The request couldn't be built, usually because the token isn't a valid header value.
+    Request(hyper::http::Error),
+    /// The request never made it to Slack, or the response body couldn't be read.
+    Transport(hyper::Error),
+    /// Slack answered with a non-2xx status.
+    Http { status: StatusCode, body: String },
+    /// Slack answered 429, and we ran out of retries waiting it out.
+    RateLimited { retry_after: Option<Duration> },
+    /// The request or response body wasn't the JSON we expected.
+    Json(serde_json::Error),
+    /// Slack answered `ok: false`.
+    Api(ApiErrorCode),
+    /// A download that isn't from files.slack.com, which the token mustn't be sent to.
+    UntrustedUrl(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Request(err) => write!(f, "couldn't build request: {}", err),
+            Error::Transport(err) => write!(f, "transport error: {}", err),
+            Error::Http { status, body } => write!(f, "http error {}: {}", status, body),
+            Error::RateLimited {
+                retry_after: Some(retry_after),
+            } => write!(f, "rate limited, retry after {:?}", retry_after),
+            Error::RateLimited { retry_after: None } => write!(f, "rate limited"),
+            Error::Json(err) => write!(f, "json error: {}", err),
+            Error::Api(code) => write!(f, "slack api error: {}", code),
+            Error::UntrustedUrl(url) => write!(f, "won't send the token to {}", url),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Request(err) => Some(err),
+            Error::Transport(err) => Some(err),
+            Error::Json(err) => Some(err),
+            Error::Http { .. }
+            | Error::RateLimited { .. }
+            | Error::Api(_)
+            | Error::UntrustedUrl(_) => None,
+        }
+    }
+}
+
+impl From<hyper::http::Error> for Error {
+    fn from(err: hyper::http::Error) -> Self {
+        Error::Request(err)
+    }
+}
+
+impl From<hyper::Error> for Error {
+    fn from(err: hyper::Error) -> Self {
+        Error::Transport(err)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Json(err)
+    }
+}
+
+/// The `error` field of an `ok: false` response.
+/// More info - https://api.slack.com/web#errors
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "String")]
+pub enum ApiErrorCode {
+    AccountInactive,
+    /// reactions.add was asked for an emoji the caller had already used on the item.
+    AlreadyReacted,
+    ChannelNotFound,
+    /// The trigger_id is more than 3 seconds old, or was already used.
+    ExpiredTriggerId,
+    FatalError,
+    /// views.update or views.publish was passed a hash for an older copy of the view.
+    HashConflict,
+    InternalError,
+    InvalidArgName,
+    InvalidArguments,
+    InvalidAuth,
+    InvalidCharset,
+    InvalidFormData,
+    InvalidPostType,
+    IsArchived,
+    MissingPostType,
+    MissingScope,
+    MsgTooLong,
+    NoPermission,
+    /// reactions.remove was asked for an emoji the caller hadn't used on the item.
+    NoReaction,
+    NoText,
+    NotAuthed,
+    NotInChannel,
+    RateLimited,
+    RequestTimeout,
+    ServiceUnavailable,
+    TeamAddedToOrg,
+    TokenRevoked,
+    TooManyAttachments,
+    /// Anything we don't have a name for yet.
+    Other(String),
+}
+
+impl ApiErrorCode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ApiErrorCode::AccountInactive => "account_inactive",
+            ApiErrorCode::AlreadyReacted => "already_reacted",
+            ApiErrorCode::ChannelNotFound => "channel_not_found",
+            ApiErrorCode::ExpiredTriggerId => "expired_trigger_id",
+            ApiErrorCode::FatalError => "fatal_error",
+            ApiErrorCode::HashConflict => "hash_conflict",
+            ApiErrorCode::InternalError => "internal_error",
+            ApiErrorCode::InvalidArgName => "invalid_arg_name",
+            ApiErrorCode::InvalidArguments => "invalid_arguments",
+            ApiErrorCode::InvalidAuth => "invalid_auth",
+            ApiErrorCode::InvalidCharset => "invalid_charset",
+            ApiErrorCode::InvalidFormData => "invalid_form_data",
+            ApiErrorCode::InvalidPostType => "invalid_post_type",
+            ApiErrorCode::IsArchived => "is_archived",
+            ApiErrorCode::MissingPostType => "missing_post_type",
+            ApiErrorCode::MissingScope => "missing_scope",
+            ApiErrorCode::MsgTooLong => "msg_too_long",
+            ApiErrorCode::NoPermission => "no_permission",
+            ApiErrorCode::NoReaction => "no_reaction",
+            ApiErrorCode::NoText => "no_text",
+            ApiErrorCode::NotAuthed => "not_authed",
+            ApiErrorCode::NotInChannel => "not_in_channel",
+            ApiErrorCode::RateLimited => "ratelimited",
+            ApiErrorCode::RequestTimeout => "request_timeout",
+            ApiErrorCode::ServiceUnavailable => "service_unavailable",
+            ApiErrorCode::TeamAddedToOrg => "team_added_to_org",
+            ApiErrorCode::TokenRevoked => "token_revoked",
+            ApiErrorCode::TooManyAttachments => "too_many_attachments",
+            ApiErrorCode::Other(code) => code,
+        }
+    }
+}
+
+impl From<String> for ApiErrorCode {
+    fn from(code: String) -> Self {
+        match &code[..] {
+            "account_inactive" => ApiErrorCode::AccountInactive,
+            "already_reacted" => ApiErrorCode::AlreadyReacted,
+            "channel_not_found" => ApiErrorCode::ChannelNotFound,
+            "expired_trigger_id" => ApiErrorCode::ExpiredTriggerId,
+            "fatal_error" => ApiErrorCode::FatalError,
+            "hash_conflict" => ApiErrorCode::HashConflict,
+            "internal_error" => ApiErrorCode::InternalError,
+            "invalid_arg_name" => ApiErrorCode::InvalidArgName,
+            "invalid_arguments" => ApiErrorCode::InvalidArguments,
+            "invalid_auth" => ApiErrorCode::InvalidAuth,
+            "invalid_charset" => ApiErrorCode::InvalidCharset,
+            "invalid_form_data" => ApiErrorCode::InvalidFormData,
+            "invalid_post_type" => ApiErrorCode::InvalidPostType,
+            "is_archived" => ApiErrorCode::IsArchived,
+            "missing_post_type" => ApiErrorCode::MissingPostType,
+            "missing_scope" => ApiErrorCode::MissingScope,
+            "msg_too_long" => ApiErrorCode::MsgTooLong,
+            "no_permission" => ApiErrorCode::NoPermission,
+            "no_reaction" => ApiErrorCode::NoReaction,
+            "no_text" => ApiErrorCode::NoText,
+            "not_authed" => ApiErrorCode::NotAuthed,
+            "not_in_channel" => ApiErrorCode::NotInChannel,
+            "ratelimited" | "rate_limited" => ApiErrorCode::RateLimited,
+            "request_timeout" => ApiErrorCode::RequestTimeout,
+            "service_unavailable" => ApiErrorCode::ServiceUnavailable,
+            "team_added_to_org" => ApiErrorCode::TeamAddedToOrg,
+            "token_revoked" => ApiErrorCode::TokenRevoked,
+            "too_many_attachments" => ApiErrorCode::TooManyAttachments,
+            _ => ApiErrorCode::Other(code),
+        }
+    }
+}
+
+impl fmt::Display for ApiErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The envelope every Web API response shares.
+#[derive(Deserialize, Debug)]
+pub(crate) struct ApiStatus {
+    pub(crate) ok: bool,
+    pub(crate) error: Option<String>,
+}
+
+/// Checks `ok` before decoding the rest of the body as `R`.
+pub(crate) fn parse_response<R>(body: &[u8]) -> Result<R>
+where
+    R: serde::de::DeserializeOwned,
+{
+    let status: ApiStatus = serde_json::from_slice(body)?;
+    if !status.ok {
+        return Err(Error::Api(
+            status
+                .error
+                .map(ApiErrorCode::from)
+                .unwrap_or_else(|| ApiErrorCode::Other("unknown_error".to_string())),
+        ));
+    }
+    Ok(serde_json::from_slice(body)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    #[test]
+    fn known_error_codes_parse() {
+        let err = parse_response::<Value>(b"{\"ok\":false,\"error\":\"channel_not_found\"}");
+        match err {
+            Err(Error::Api(ApiErrorCode::ChannelNotFound)) => (),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn unknown_error_codes_fall_back() {
+        let err = parse_response::<Value>(b"{\"ok\":false,\"error\":\"brand_new_error\"}");
+        match err {
+            Err(Error::Api(ApiErrorCode::Other(code))) => assert_eq!("brand_new_error", code),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn ok_responses_decode() {
+        let ok = parse_response::<Value>(b"{\"ok\":true,\"channel\":\"C1234\"}").unwrap();
+        assert_eq!("C1234", ok["channel"]);
+    }
+}
@@ -0,0 +1,149 @@
+//! The different shapes a message event can take.
+//! More info - https://api.slack.com/events/message#message_subtypes
+use super::{File, MessageBlock};
+use crate::{BotId, TeamId, Ts, UserId};
+use log::{debug, warn};
+use serde::{de::Error as _, Deserialize, Deserializer};
+use serde_json::Value;
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(remote = "Self", tag = "subtype", rename_all = "snake_case")]
+pub enum MessageSubtype {
+    /// A regular message from a person, Slack leaves subtype off of these.
+    #[serde(skip_deserializing)]
+    Plain(UserMessage),
+    /// A message was posted by an integration
+    BotMessage {
+        bot_id: BotId,
+        /// Set when the bot has a user, e.g. when posting with as_user
+        user: Option<UserId>,
+        username: Option<String>,
+        text: String,
+        #[serde(default)]
+        blocks: Vec<MessageBlock>,
+    },
+    /// A message was changed
+    MessageChanged {
+        message: MessageSnapshot,
+        previous_message: MessageSnapshot,
+        #[serde(default)]
+        hidden: bool,
+    },
+    /// A message was deleted
+    MessageDeleted {
+        deleted_ts: Ts,
+        previous_message: Option<MessageSnapshot>,
+        #[serde(default)]
+        hidden: bool,
+    },
+    /// A member joined a channel
+    ChannelJoin {
+        user: UserId,
+        text: String,
+        /// Missing when the user joined on their own
+        inviter: Option<UserId>,
+    },
+    /// A message thread reply was broadcast to the channel
+    ThreadBroadcast {
+        client_msg_id: Option<String>,
+        user: UserId,
+        text: String,
+        #[serde(default)]
+        blocks: Vec<MessageBlock>,
+        /// The message at the top of the thread
+        root: Option<MessageSnapshot>,
+    },
+    /// A file was shared into a channel
+    FileShare {
+        user: UserId,
+        text: String,
+        #[serde(default)]
+        files: Vec<File>,
+        /// True when the file was uploaded with this message rather than shared from elsewhere
+        #[serde(default)]
+        upload: bool,
+        #[serde(default)]
+        blocks: Vec<MessageBlock>,
+    },
+    /// A /me message was sent
+    MeMessage { user: UserId, text: String },
+    /// Any subtype we don't model yet, like channel_topic or pinned_item.
+    #[serde(skip_deserializing)]
+    Other {
+        #[serde(skip)]
+        subtype: String,
+        #[serde(skip)]
+        raw: Value,
+    },
+}
+
+impl MessageSubtype {
+    /// Every `subtype` the derived deserializer reads, anything else is Other.
+    pub(crate) const SUBTYPES: &'static [&'static str] = &[
+        "bot_message",
+        "message_changed",
+        "message_deleted",
+        "channel_join",
+        "thread_broadcast",
+        "file_share",
+        "me_message",
+    ];
+}
+
+impl<'de> Deserialize<'de> for MessageSubtype {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = Value::deserialize(deserializer)?;
+        let subtype = match raw.get("subtype").and_then(Value::as_str) {
+            Some(subtype) => subtype.to_string(),
+            None => {
+                return UserMessage::deserialize(&raw)
+                    .map(MessageSubtype::Plain)
+                    .map_err(D::Error::custom)
+            }
+        };
+        if !MessageSubtype::SUBTYPES.contains(&&subtype[..]) {
+            debug!("falling back to Other for {}", subtype);
+            return Ok(MessageSubtype::Other { subtype, raw });
+        }
+        MessageSubtype::deserialize(&raw).map_err(|err| {
+            warn!("couldn't deserialize {} message - {}", subtype, err);
+            D::Error::custom(err)
+        })
+    }
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct UserMessage {
+    pub client_msg_id: Option<String>,
+    pub user: UserId,
+    /// Set when an app posted as its bot user without marking it a bot_message
+    pub bot_id: Option<BotId>,
+    pub text: String,
+    pub team: Option<TeamId>,
+    #[serde(default)]
+    pub blocks: Vec<MessageBlock>,
+}
+
+/// A message as it was before or after an edit, or at the root of a thread.
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct MessageSnapshot {
+    pub subtype: Option<String>,
+    pub client_msg_id: Option<String>,
+    pub user: Option<UserId>,
+    pub bot_id: Option<BotId>,
+    pub text: String,
+    pub ts: Ts,
+    pub thread_ts: Option<Ts>,
+    pub edited: Option<Edited>,
+    #[serde(default)]
+    pub blocks: Vec<MessageBlock>,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct Edited {
+    pub user: UserId,
+    pub ts: Ts,
+}
@@ -1,8 +1,20 @@
-use serde::Deserialize;
+use crate::blocks::Block;
+use crate::{AppId, ChannelId, EventId, TeamId, Ts, UserId};
+use log::{debug, warn};
+use serde::{Deserialize, Deserializer};
 use serde_json::Value;
 
+mod message;
+mod objects;
+pub use message::*;
+pub use objects::*;
+
+/// What Slack POSTs to your Events API request URL.
+///
+/// `remote = "Self"` makes the inherent `OuterEvent::deserialize` the derived impl, call the
+/// `Deserialize` trait (or `serde_json::from_*`) to get the `Unknown` fallback.
 #[derive(Deserialize, Debug, PartialEq)]
-#[serde(tag = "type", rename_all = "snake_case")]
+#[serde(remote = "Self", tag = "type", rename_all = "snake_case")]
 pub enum OuterEvent {
     UrlVerification {
         token: String,
@@ -10,186 +22,279 @@ pub enum OuterEvent {
     },
     EventCallback {
         token: String,
-        team_id: String,
-        api_app_id: String,
+        team_id: TeamId,
+        api_app_id: AppId,
         event: InnerEvent,
-        authed_users: Option<Vec<String>>,
-        event_id: String,
+        authed_users: Option<Vec<UserId>>,
+        event_id: EventId,
         event_time: u64,
     },
+    /// Your app's event subscriptions are being rate limited
+    AppRateLimited {
+        token: String,
+        team_id: TeamId,
+        minute_rate_limited: u64,
+        api_app_id: AppId,
+    },
+    /// Anything we couldn't parse, with the original payload so it can still be
+    /// logged or forwarded.
+    #[serde(skip_deserializing)]
+    Unknown {
+        #[serde(skip)]
+        r#type: String,
+        #[serde(skip)]
+        raw: Value,
+    },
+}
+
+impl OuterEvent {
+    /// Every `type` the derived deserializer reads, anything else is Unknown.
+    pub(crate) const TYPES: &'static [&'static str] =
+        &["url_verification", "event_callback", "app_rate_limited"];
+}
+
+impl<'de> Deserialize<'de> for OuterEvent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = Value::deserialize(deserializer)?;
+        with_fallback(
+            raw,
+            OuterEvent::TYPES,
+            |raw| OuterEvent::deserialize(raw),
+            |r#type, raw| OuterEvent::Unknown { r#type, raw },
+        )
+    }
 }
 
 #[derive(Deserialize, Debug, PartialEq)]
-#[serde(tag = "type", rename_all = "snake_case")]
+#[serde(remote = "Self", tag = "type", rename_all = "snake_case")]
 pub enum InnerEvent {
     /// User clicked into your App Home
     /// Permissions: None
     AppHomeOpened {
-        user: String,
-        channel: String,
-        event_ts: Option<f32>,
+        user: UserId,
+        channel: ChannelId,
+        event_ts: Option<Ts>,
         tab: String,
         view: Option<Value>,
     },
     /// Subscribe to only the message events that mention your app or bot
     /// Permissions: app_mentions:read
     AppMention {
-        user: String,
+        user: UserId,
         text: String,
-        ts: f32,
-        channel: String,
-        event_ts: u64,
+        ts: Ts,
+        channel: ChannelId,
+        event_ts: Ts,
     },
     /// Indicates your app's event subscriptions are being rate limited
     /// Permissions: None
     AppRateLimited {
         token: String,
-        team_id: String,
+        team_id: TeamId,
         minute_rate_limited: u64,
-        api_app_id: String,
+        api_app_id: AppId,
     },
     /// User requested an app
     /// Permissions: admin.apps:read
-    AppRequested {}, // I'll get back to this.
+    AppRequested { app_request: AppRequest },
     /// Your Slack app was uninstalled.
     /// Permissions: None
     AppUninstalled {},
     /// A call was rejected
     /// No Documentation provided for this event
     /// Permissions: calls:read
-    CallRejected { channel: Channel },
+    CallRejected {
+        call_id: String,
+        user_id: UserId,
+        channel_id: ChannelId,
+        external_unique_id: String,
+    },
     /// A channel was archived
     /// Permissions: channels:read
-    ChannelArchive { channel: String, user: String },
+    ChannelArchive { channel: ChannelId, user: UserId },
     /// A channel was created
     /// Permissions: channels:read
-    ChannelCreated {},
+    ChannelCreated { channel: Channel },
     /// A channel was deleted
     /// Permissions: channels:read
-    ChannelDeleted {},
+    ChannelDeleted { channel: ChannelId },
     /// Bulk updates were made to a channel's history
     /// Permissions: channels:history
-    ChannelHistoryChanged {},
+    ChannelHistoryChanged { latest: Ts, ts: Ts, event_ts: Ts },
     /// You left a channel
     /// Permissions: channels:read
-    ChannelLeft {},
+    ChannelLeft { channel: ChannelId },
     /// A channel was renamed
     /// Permissions: channels:read
-    ChannelRename {},
+    ChannelRename { channel: RenamedChannel },
     /// A channel has been shared with an external workspace
     /// Permissions: channels:read
-    ChannelShared {},
+    ChannelShared {
+        connected_team_id: TeamId,
+        channel: ChannelId,
+        event_ts: Ts,
+    },
     /// A channel was unarchived
     /// Permissions: channels:read
-    ChannelUnarchive {},
+    ChannelUnarchive { channel: ChannelId, user: UserId },
     /// A channel has been unshared with an external workspace
     /// Permissions: channels:read
-    ChannelUnshared {},
+    ChannelUnshared {
+        previously_connected_team_id: TeamId,
+        channel: ChannelId,
+        is_ext_shared: bool,
+        event_ts: Ts,
+    },
     /// Do not Disturb settings changed for the current user
     /// Permissions: dnd:read
-    DndUpdated {},
+    DndUpdated { user: UserId, dnd_status: DndStatus },
     /// Do not Disturb settings changed for a member
     /// Permissions: dnd:read
-    DndUpdatedUser {},
+    DndUpdatedUser { user: UserId, dnd_status: DndStatus },
     /// The workspace email domain has changed
     /// Permissions: team:read
-    EmailDomainChanged {},
+    EmailDomainChanged { email_domain: String, event_ts: Ts },
     /// A custom emoji has been added or changed
     /// Permissions: emoji:read
-    EmojiChanged {},
+    EmojiChanged {
+        #[serde(flatten)]
+        change: EmojiChange,
+        event_ts: Ts,
+    },
     /// A file was changed
     /// Permissions: files:read
-    FileChange {},
+    FileChange { file_id: String, file: FileRef },
     /// A file comment was added
     /// Permissions: files:read
-    FileCommentAdded {},
+    FileCommentAdded {
+        comment: Value,
+        file_id: String,
+        file: FileRef,
+    },
     /// A file comment was deleted
     /// Permissions: files:read
-    FileCommentDeleted {},
+    FileCommentDeleted {
+        /// The id of the deleted comment
+        comment: String,
+        file_id: String,
+        file: FileRef,
+    },
     /// A file comment was edited
     /// Permissions: files:read
-    FileCommentEdited {},
+    FileCommentEdited {
+        comment: Value,
+        file_id: String,
+        file: FileRef,
+    },
     /// A file was created
     /// Permissions: files:read
-    FileCreated {},
+    FileCreated { file_id: String, file: FileRef },
     /// A file was deleted
     /// Permissions: files:read
-    FileDeleted {},
+    FileDeleted { file_id: String, event_ts: Ts },
     /// A file was made public
     /// Permissions: files:read
-    FilePublic {},
+    FilePublic { file_id: String, file: FileRef },
     /// A file was shared
     /// Permissions: files:read
-    FileShared {},
+    FileShared {
+        file_id: String,
+        user_id: UserId,
+        file: FileRef,
+        channel_id: Option<ChannelId>,
+        event_ts: Ts,
+    },
     /// A file was unshared
     /// Permissions: files:read
-    FileUnshared {},
+    FileUnshared { file_id: String, file: FileRef },
     /// An enterprise grid migration has finished on this workspace.
     /// Permissions: None
-    GridMigrationFinished {},
+    GridMigrationFinished { enterprise_id: String },
     /// An enterprise grid migration has started on this workspace.
     /// Permissions: None
-    GridMigrationStarted {},
+    GridMigrationStarted { enterprise_id: String },
     /// A private channel was archived
     /// Permissions: groups:read
-    GroupArchive {},
+    GroupArchive { channel: ChannelId },
     /// You closed a private channel
     /// Permissions: groups:read
-    GroupClose {},
+    GroupClose { user: UserId, channel: ChannelId },
     /// A private channel was deleted
     /// Permissions: groups:read
-    GroupDeleted {},
+    GroupDeleted { channel: ChannelId },
     /// Bulk updates were made to a private channel's history
     /// Permissions: groups:history
-    GroupHistoryChanged {},
+    GroupHistoryChanged { latest: Ts, ts: Ts, event_ts: Ts },
     /// You left a private channel
     /// Permissions: groups:read
-    GroupLeft {},
+    GroupLeft { channel: ChannelId },
     /// You created a group DM
     /// Permissions: groups:read
-    GroupOpen {},
+    GroupOpen { user: UserId, channel: ChannelId },
     /// A private channel was renamed
     /// Permissions: groups:read
-    GroupRename {},
+    GroupRename { channel: RenamedChannel },
     /// A private channel was unarchived
     /// Permissions: groups:read
-    GroupUnarchive {},
+    GroupUnarchive { channel: ChannelId },
     /// You closed a DM
     /// Permissions: im:read
-    ImClose {},
+    ImClose { user: UserId, channel: ChannelId },
     /// A DM was created
     /// Permissions: im:read
-    ImCreated {},
+    ImCreated { user: UserId, channel: ImChannel },
     /// Bulk updates were made to a DM's history
     /// Permissions: im:history
-    ImHistoryChanged {},
+    ImHistoryChanged { latest: Ts, ts: Ts, event_ts: Ts },
     /// You opened a DM
     /// Permissions: im:read
-    ImOpen {},
+    ImOpen { user: UserId, channel: ChannelId },
     /// User requested an invite
     /// Permissions: admin.invites:read
-    InviteRequested {},
+    InviteRequested { invite_request: InviteRequest },
     /// A message was posted containing one or more links relevant to your application
     /// Permissions: links:read
-    LinkShared {},
+    LinkShared {
+        channel: ChannelId,
+        user: UserId,
+        message_ts: Ts,
+        thread_ts: Option<Ts>,
+        links: Vec<SharedLink>,
+    },
     /// A user joined a public or private channel
     /// Permissions: channels:read
-    MemberJoinedChannel {},
+    MemberJoinedChannel {
+        user: UserId,
+        channel: ChannelId,
+        channel_type: String,
+        team: TeamId,
+        /// Missing when the user joined on their own
+        inviter: Option<UserId>,
+    },
     /// A user left a public or private channel
     /// Permissions: channels:read
-    MemberLeftChannel {},
+    MemberLeftChannel {
+        user: UserId,
+        channel: ChannelId,
+        channel_type: String,
+        team: TeamId,
+    },
     /// A message was sent to a channel
     /// Permissions: channels:history
     Message {
-        client_msg_id: String,
-        text: String,
-        user: String,
-        ts: String, // Float?
-        team: String,
-        channel: String,
-        event_ts: String,
-        channel_type: String, //Enum (im?)
-        blocks: Vec<MessageBlock>,
+        ts: Ts,
+        channel: ChannelId,
+        event_ts: Ts,
+        channel_type: String,
+        /// Set on replies, the ts of the message that started the thread
+        thread_ts: Option<Ts>,
+        /// Set on replies, who started the thread
+        parent_user_id: Option<UserId>,
+        #[serde(flatten)]
+        subtype: MessageSubtype,
     },
     /// message.app_home
     /// A user sent a message to your Slack app
@@ -208,115 +313,478 @@ pub enum InnerEvent {
     /// mpim:history
     /// A pin was added to a channel
     /// pins:read
-    PinAdded {},
+    PinAdded {
+        user: UserId,
+        channel_id: Option<ChannelId>,
+        item: Option<SavedItem>,
+        event_ts: Ts,
+    },
     /// A pin was removed from a channel
     /// Permissions: pins:read
-    PinRemoved {},
+    PinRemoved {
+        user: UserId,
+        channel_id: Option<ChannelId>,
+        item: Option<SavedItem>,
+        /// Whether anything is still pinned in the channel
+        has_pins: Option<bool>,
+        event_ts: Ts,
+    },
     /// A member has added an emoji reaction to an item
     /// Permissions: reactions:read
-    ReactionAdded {},
+    ReactionAdded {
+        user: UserId,
+        reaction: String,
+        /// Who created the item that was reacted to
+        item_user: Option<UserId>,
+        item: Item,
+        event_ts: Ts,
+    },
     /// A member removed an emoji reaction
     /// Permissions: reactions:read
-    ReactionRemoved {},
+    ReactionRemoved {
+        user: UserId,
+        reaction: String,
+        /// Who created the item that was reacted to
+        item_user: Option<UserId>,
+        item: Item,
+        event_ts: Ts,
+    },
     /// Access to a set of resources was granted for your app
     /// Permissions: None
-    ResourcesAdded {},
+    ResourcesAdded { resources: Vec<GrantedResource> },
     /// Access to a set of resources was removed for your app
     /// Permissions: None
-    ResourcesRemoved {},
+    ResourcesRemoved { resources: Vec<GrantedResource> },
     /// OAuth scopes were denied to your app
     /// Permissions: None
-    ScopeDenied {},
+    ScopeDenied {
+        scopes: Vec<String>,
+        trigger_id: String,
+    },
     /// OAuth scopes were granted to your app
     /// Permissions: None
-    ScopeGranted {},
+    ScopeGranted {
+        scopes: Vec<String>,
+        trigger_id: String,
+    },
     /// A member has starred an item
     /// Permissions: stars:read
-    StarAdded {},
+    StarAdded {
+        user: UserId,
+        item: SavedItem,
+        event_ts: Ts,
+    },
     /// A member removed a star
     /// Permissions: stars:read
-    StarRemoved {},
+    StarRemoved {
+        user: UserId,
+        item: SavedItem,
+        event_ts: Ts,
+    },
     /// A User Group has been added to the workspace
     /// Permissions: usergroups:read
-    SubteamCreated {},
+    SubteamCreated { subteam: Subteam },
     /// The membership of an existing User Group has changed
     /// Permissions: usergroups:read
-    SubteamMembersChanged {},
+    SubteamMembersChanged {
+        subteam_id: String,
+        team_id: TeamId,
+        date_previous_update: u64,
+        date_update: u64,
+        #[serde(default)]
+        added_users: Vec<UserId>,
+        #[serde(default)]
+        added_users_count: u32,
+        #[serde(default)]
+        removed_users: Vec<UserId>,
+        #[serde(default)]
+        removed_users_count: u32,
+    },
     /// You have been added to a User Group
     /// Permissions: usergroups:read
-    SubteamSelfAdded {},
+    SubteamSelfAdded { subteam_id: String },
     /// You have been removed from a User Group
     /// Permissions: usergroups:read
-    SubteamSelfRemoved {},
+    SubteamSelfRemoved { subteam_id: String },
     /// An existing User Group has been updated or its members changed
     /// Permissions: usergroups:read
-    SubteamUpdated {},
+    SubteamUpdated { subteam: Subteam },
     /// The workspace domain has changed
     /// Permissions: team:read
-    TeamDomainChange {},
+    TeamDomainChange { url: String, domain: String },
     /// A new member has joined
     /// Permissions: users:read
-    TeamJoin {},
+    TeamJoin { user: User },
     /// The workspace name has changed
     /// Permissions: team:read
-    TeamRename {},
+    TeamRename { name: String },
     /// API tokens for your app were revoked.
     /// Permissions: None
-    TokensRevoked {},
+    TokensRevoked { tokens: RevokedTokens },
     /// Verifies ownership of an Events API Request URL
     /// Permissions: None
-    UrlVerification {},
+    UrlVerification { token: String, challenge: String },
     /// A member's data has changed
     /// Permissions: users:read
-    UserChange {},
+    UserChange { user: User },
     /// User resource was denied to your app
     /// Permissions: None
-    UserResourceDenied {},
+    UserResourceDenied {
+        user: UserId,
+        scopes: Vec<String>,
+        trigger_id: String,
+    },
     /// User resource was granted to your app
     /// Permissions: None
-    UserResourceGranted {},
+    UserResourceGranted {
+        user: UserId,
+        scopes: Vec<String>,
+        trigger_id: String,
+    },
     /// User resource was removed from your app
     /// Permissions: None
-    UserResourceRemoved {},
+    UserResourceRemoved { user: UserId, trigger_id: String },
+    /// An event type we don't know about yet, or one whose payload didn't match what
+    /// we expected. raw is the whole event so it can still be routed.
+    #[serde(skip_deserializing)]
+    Unknown {
+        #[serde(skip)]
+        r#type: String,
+        #[serde(skip)]
+        raw: Value,
+    },
 }
 
-#[derive(Deserialize, Debug, PartialEq)]
-#[serde(tag = "type", rename_all = "snake_case")]
-pub struct Channel {
-    id: String,
-    name: String,
-    created: u64,
-    creator: User,
+impl InnerEvent {
+    /// Every `type` the derived deserializer reads, anything else is Unknown.
+    pub(crate) const TYPES: &'static [&'static str] = &[
+        "app_home_opened",
+        "app_mention",
+        "app_rate_limited",
+        "app_requested",
+        "app_uninstalled",
+        "call_rejected",
+        "channel_archive",
+        "channel_created",
+        "channel_deleted",
+        "channel_history_changed",
+        "channel_left",
+        "channel_rename",
+        "channel_shared",
+        "channel_unarchive",
+        "channel_unshared",
+        "dnd_updated",
+        "dnd_updated_user",
+        "email_domain_changed",
+        "emoji_changed",
+        "file_change",
+        "file_comment_added",
+        "file_comment_deleted",
+        "file_comment_edited",
+        "file_created",
+        "file_deleted",
+        "file_public",
+        "file_shared",
+        "file_unshared",
+        "grid_migration_finished",
+        "grid_migration_started",
+        "group_archive",
+        "group_close",
+        "group_deleted",
+        "group_history_changed",
+        "group_left",
+        "group_open",
+        "group_rename",
+        "group_unarchive",
+        "im_close",
+        "im_created",
+        "im_history_changed",
+        "im_open",
+        "invite_requested",
+        "link_shared",
+        "member_joined_channel",
+        "member_left_channel",
+        "message",
+        "pin_added",
+        "pin_removed",
+        "reaction_added",
+        "reaction_removed",
+        "resources_added",
+        "resources_removed",
+        "scope_denied",
+        "scope_granted",
+        "star_added",
+        "star_removed",
+        "subteam_created",
+        "subteam_members_changed",
+        "subteam_self_added",
+        "subteam_self_removed",
+        "subteam_updated",
+        "team_domain_change",
+        "team_join",
+        "team_rename",
+        "tokens_revoked",
+        "url_verification",
+        "user_change",
+        "user_resource_denied",
+        "user_resource_granted",
+        "user_resource_removed",
+    ];
+
+    /// The event's `type`, e.g. `reaction_added`.
+    pub fn event_type(&self) -> &str {
+        match self {
+            InnerEvent::AppHomeOpened { .. } => "app_home_opened",
+            InnerEvent::AppMention { .. } => "app_mention",
+            InnerEvent::AppRateLimited { .. } => "app_rate_limited",
+            InnerEvent::AppRequested { .. } => "app_requested",
+            InnerEvent::AppUninstalled { .. } => "app_uninstalled",
+            InnerEvent::CallRejected { .. } => "call_rejected",
+            InnerEvent::ChannelArchive { .. } => "channel_archive",
+            InnerEvent::ChannelCreated { .. } => "channel_created",
+            InnerEvent::ChannelDeleted { .. } => "channel_deleted",
+            InnerEvent::ChannelHistoryChanged { .. } => "channel_history_changed",
+            InnerEvent::ChannelLeft { .. } => "channel_left",
+            InnerEvent::ChannelRename { .. } => "channel_rename",
+            InnerEvent::ChannelShared { .. } => "channel_shared",
+            InnerEvent::ChannelUnarchive { .. } => "channel_unarchive",
+            InnerEvent::ChannelUnshared { .. } => "channel_unshared",
+            InnerEvent::DndUpdated { .. } => "dnd_updated",
+            InnerEvent::DndUpdatedUser { .. } => "dnd_updated_user",
+            InnerEvent::EmailDomainChanged { .. } => "email_domain_changed",
+            InnerEvent::EmojiChanged { .. } => "emoji_changed",
+            InnerEvent::FileChange { .. } => "file_change",
+            InnerEvent::FileCommentAdded { .. } => "file_comment_added",
+            InnerEvent::FileCommentDeleted { .. } => "file_comment_deleted",
+            InnerEvent::FileCommentEdited { .. } => "file_comment_edited",
+            InnerEvent::FileCreated { .. } => "file_created",
+            InnerEvent::FileDeleted { .. } => "file_deleted",
+            InnerEvent::FilePublic { .. } => "file_public",
+            InnerEvent::FileShared { .. } => "file_shared",
+            InnerEvent::FileUnshared { .. } => "file_unshared",
+            InnerEvent::GridMigrationFinished { .. } => "grid_migration_finished",
+            InnerEvent::GridMigrationStarted { .. } => "grid_migration_started",
+            InnerEvent::GroupArchive { .. } => "group_archive",
+            InnerEvent::GroupClose { .. } => "group_close",
+            InnerEvent::GroupDeleted { .. } => "group_deleted",
+            InnerEvent::GroupHistoryChanged { .. } => "group_history_changed",
+            InnerEvent::GroupLeft { .. } => "group_left",
+            InnerEvent::GroupOpen { .. } => "group_open",
+            InnerEvent::GroupRename { .. } => "group_rename",
+            InnerEvent::GroupUnarchive { .. } => "group_unarchive",
+            InnerEvent::ImClose { .. } => "im_close",
+            InnerEvent::ImCreated { .. } => "im_created",
+            InnerEvent::ImHistoryChanged { .. } => "im_history_changed",
+            InnerEvent::ImOpen { .. } => "im_open",
+            InnerEvent::InviteRequested { .. } => "invite_requested",
+            InnerEvent::LinkShared { .. } => "link_shared",
+            InnerEvent::MemberJoinedChannel { .. } => "member_joined_channel",
+            InnerEvent::MemberLeftChannel { .. } => "member_left_channel",
+            InnerEvent::Message { .. } => "message",
+            InnerEvent::PinAdded { .. } => "pin_added",
+            InnerEvent::PinRemoved { .. } => "pin_removed",
+            InnerEvent::ReactionAdded { .. } => "reaction_added",
+            InnerEvent::ReactionRemoved { .. } => "reaction_removed",
+            InnerEvent::ResourcesAdded { .. } => "resources_added",
+            InnerEvent::ResourcesRemoved { .. } => "resources_removed",
+            InnerEvent::ScopeDenied { .. } => "scope_denied",
+            InnerEvent::ScopeGranted { .. } => "scope_granted",
+            InnerEvent::StarAdded { .. } => "star_added",
+            InnerEvent::StarRemoved { .. } => "star_removed",
+            InnerEvent::SubteamCreated { .. } => "subteam_created",
+            InnerEvent::SubteamMembersChanged { .. } => "subteam_members_changed",
+            InnerEvent::SubteamSelfAdded { .. } => "subteam_self_added",
+            InnerEvent::SubteamSelfRemoved { .. } => "subteam_self_removed",
+            InnerEvent::SubteamUpdated { .. } => "subteam_updated",
+            InnerEvent::TeamDomainChange { .. } => "team_domain_change",
+            InnerEvent::TeamJoin { .. } => "team_join",
+            InnerEvent::TeamRename { .. } => "team_rename",
+            InnerEvent::TokensRevoked { .. } => "tokens_revoked",
+            InnerEvent::UrlVerification { .. } => "url_verification",
+            InnerEvent::UserChange { .. } => "user_change",
+            InnerEvent::UserResourceDenied { .. } => "user_resource_denied",
+            InnerEvent::UserResourceGranted { .. } => "user_resource_granted",
+            InnerEvent::UserResourceRemoved { .. } => "user_resource_removed",
+            InnerEvent::Unknown { r#type, .. } => r#type,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for InnerEvent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = Value::deserialize(deserializer)?;
+        with_fallback(
+            raw,
+            InnerEvent::TYPES,
+            |raw| InnerEvent::deserialize(raw),
+            |r#type, raw| InnerEvent::Unknown { r#type, raw },
+        )
+    }
 }
 
-type User = String;
+/// Hands back the raw JSON through `unknown` if `types` doesn't have its `type`, and
+/// otherwise uses the derived deserializer. A missing `type`, or a type we know that doesn't
+/// match its model, is still an error.
+pub(crate) fn with_fallback<T, E, K, U>(
+    raw: Value,
+    types: &[&str],
+    known: K,
+    unknown: U,
+) -> Result<T, E>
+where
+    E: serde::de::Error,
+    K: FnOnce(&Value) -> Result<T, serde_json::Error>,
+    U: FnOnce(String, Value) -> T,
+{
+    let r#type = match raw.get("type").and_then(Value::as_str) {
+        Some(r#type) => r#type.to_string(),
+        None => return Err(E::missing_field("type")),
+    };
+    if !types.contains(&&r#type[..]) {
+        debug!("falling back to Unknown for {}", r#type);
+        return Ok(unknown(r#type, raw));
+    }
+    known(&raw).map_err(|err| {
+        warn!("couldn't deserialize {} - {}", r#type, err);
+        E::custom(err)
+    })
+}
 
 #[derive(Deserialize, Debug, PartialEq)]
-#[serde(tag = "type", rename_all = "snake_case")]
+#[serde(remote = "Self", tag = "type", rename_all = "snake_case")]
 pub enum MessageBlock {
     RichText {
         block_id: String,
         elements: Vec<RichTextElement>,
     },
+    /// Any other Block Kit block, usually posted by a bot
+    #[serde(skip_deserializing)]
+    Layout(Block),
+    /// A block we couldn't make sense of
+    #[serde(skip_deserializing)]
+    Unknown(Value),
+}
+
+impl<'de> Deserialize<'de> for MessageBlock {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = Value::deserialize(deserializer)?;
+        let block = if raw.get("type").and_then(Value::as_str) == Some("rich_text") {
+            MessageBlock::deserialize(&raw).ok()
+        } else {
+            Block::deserialize(&raw).map(MessageBlock::Layout).ok()
+        };
+        Ok(block.unwrap_or(MessageBlock::Unknown(raw)))
+    }
 }
 
+// Variant names mirror Slack's type tags.
+#[allow(clippy::enum_variant_names)]
 #[derive(Deserialize, Debug, PartialEq)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum RichTextElement {
+    /// A run of inline elements, i.e. a paragraph
     RichTextSection {
         elements: Vec<RichTextSectionElement>,
     },
+    /// A bulleted or numbered list, each item is a RichTextSection
+    RichTextList {
+        elements: Vec<RichTextElement>,
+        style: RichTextListStyle,
+        #[serde(default)]
+        indent: u32,
+        #[serde(default)]
+        offset: u32,
+        #[serde(default)]
+        border: u32,
+    },
+    /// A code block
+    RichTextPreformatted {
+        elements: Vec<RichTextSectionElement>,
+        #[serde(default)]
+        border: u32,
+    },
+    /// A block quote
+    RichTextQuote {
+        elements: Vec<RichTextSectionElement>,
+        #[serde(default)]
+        border: u32,
+    },
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RichTextListStyle {
+    Bullet,
+    Ordered,
 }
 
 #[derive(Deserialize, Debug, PartialEq)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum RichTextSectionElement {
-    Text { text: String },
+    Text {
+        text: String,
+        style: Option<TextStyle>,
+    },
+    Link {
+        url: String,
+        /// Only present when the link text differs from the url
+        text: Option<String>,
+        style: Option<TextStyle>,
+    },
+    /// An @mention of a user
+    User {
+        user_id: UserId,
+        style: Option<TextStyle>,
+    },
+    /// A #channel reference
+    Channel {
+        channel_id: ChannelId,
+        style: Option<TextStyle>,
+    },
+    /// An @mention of a user group
+    Usergroup {
+        usergroup_id: String,
+        style: Option<TextStyle>,
+    },
+    Emoji {
+        /// The name without colons, i.e. `thumbsup`
+        name: String,
+        /// Code points for standard emoji, missing for custom emoji
+        unicode: Option<String>,
+        skin_tone: Option<u8>,
+    },
+    /// @here, @channel or @everyone
+    Broadcast { range: BroadcastRange },
+}
+
+#[derive(Deserialize, Debug, PartialEq, Default)]
+#[serde(default)]
+pub struct TextStyle {
+    pub bold: bool,
+    pub italic: bool,
+    pub strike: bool,
+    pub code: bool,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BroadcastRange {
+    Here,
+    Channel,
+    Everyone,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde::de::value::MapDeserializer;
+    use std::vec::IntoIter;
     // use serde_json::{map::Map, Value};
 
     #[test]
@@ -361,7 +829,12 @@ mod tests {
                 token: "XXYYZZ".into(),
                 team_id: "TXXXXXXXX".into(),
                 api_app_id: "AXXXXXXXXX".into(),
-                event: InnerEvent::PinAdded {},
+                event: InnerEvent::PinAdded {
+                    user: "UXXXXXXX1".into(),
+                    channel_id: None,
+                    item: None,
+                    event_ts: ts("1234567890.123456"),
+                },
                 authed_users: Some(vec!["UXXXXXXX1".into(), "UXXXXXXX2".into()]),
                 event_id: "Ev08MFMKH6".into(),
                 event_time: 1_234_567_890
@@ -434,22 +907,28 @@ mod tests {
                 team_id: "T010346TVPH".into(),
                 api_app_id: "A0103EF7Y3G".into(),
                 event: InnerEvent::Message {
-                    client_msg_id: "a5899740-233f-4656-8469-5f88c5b8db27".into(),
-                    text: "hello?".into(),
-                    user: "U0103ED6A22".into(),
-                    ts: "1584339455.000200".into(),
-                    team: "T010346TVPH".into(),
+                    ts: ts("1584339455.000200"),
                     channel: "D0103EVPKTQ".into(),
-                    event_ts: "1584339455.000200".into(),
+                    event_ts: ts("1584339455.000200"),
                     channel_type: "im".into(),
-                    blocks: vec![MessageBlock::RichText {
-                        block_id: "XCSy".into(),
-                        elements: vec![RichTextElement::RichTextSection {
-                            elements: vec![RichTextSectionElement::Text {
-                                text: "hello?".into()
+                    thread_ts: None,
+                    parent_user_id: None,
+                    subtype: MessageSubtype::Plain(UserMessage {
+                        client_msg_id: Some("a5899740-233f-4656-8469-5f88c5b8db27".into()),
+                        text: "hello?".into(),
+                        user: "U0103ED6A22".into(),
+                        bot_id: None,
+                        team: Some("T010346TVPH".into()),
+                        blocks: vec![MessageBlock::RichText {
+                            block_id: "XCSy".into(),
+                            elements: vec![RichTextElement::RichTextSection {
+                                elements: vec![RichTextSectionElement::Text {
+                                    text: "hello?".into(),
+                                    style: None
+                                }]
                             }]
                         }]
-                    }]
+                    })
                 },
                 authed_users: Some(vec!["U01018PDSNL".into()]),
                 event_id: "Ev0103PNN1L7".into(),
@@ -458,4 +937,1889 @@ mod tests {
             callback
         );
     }
+
+    #[test]
+    fn rich_text_works() {
+        let blocks = "[{
+            \"type\":\"rich_text\",
+            \"block_id\":\"Gq2\",
+            \"elements\":[
+                {
+                    \"type\":\"rich_text_section\",
+                    \"elements\":[
+                        {\"type\":\"text\",\"text\":\"hey \"},
+                        {\"type\":\"user\",\"user_id\":\"U0103ED6A22\"},
+                        {\"type\":\"text\",\"text\":\" look at \",\"style\":{\"bold\":true}},
+                        {\"type\":\"link\",\"url\":\"https://api.slack.com\",\"text\":\"the docs\"},
+                        {\"type\":\"text\",\"text\":\" in \"},
+                        {\"type\":\"channel\",\"channel_id\":\"C0103EVPKTQ\"},
+                        {\"type\":\"usergroup\",\"usergroup_id\":\"S0614TZR7\"},
+                        {\"type\":\"broadcast\",\"range\":\"here\"},
+                        {\"type\":\"emoji\",\"name\":\"thumbsup\",\"unicode\":\"1f44d\",\"skin_tone\":2}
+                    ]
+                },
+                {
+                    \"type\":\"rich_text_list\",
+                    \"style\":\"ordered\",
+                    \"indent\":1,
+                    \"elements\":[{
+                        \"type\":\"rich_text_section\",
+                        \"elements\":[{\"type\":\"text\",\"text\":\"first\",\"style\":{\"italic\":true,\"strike\":true}}]
+                    }]
+                },
+                {
+                    \"type\":\"rich_text_preformatted\",
+                    \"border\":0,
+                    \"elements\":[{\"type\":\"text\",\"text\":\"let x = 1;\"}]
+                },
+                {
+                    \"type\":\"rich_text_quote\",
+                    \"elements\":[{\"type\":\"text\",\"text\":\"quoted\",\"style\":{\"code\":true}}]
+                }
+            ]
+        }]";
+        let blocks: Vec<MessageBlock> = serde_json::from_str(blocks).unwrap();
+        assert_eq!(
+            vec![MessageBlock::RichText {
+                block_id: "Gq2".into(),
+                elements: vec![
+                    RichTextElement::RichTextSection {
+                        elements: vec![
+                            RichTextSectionElement::Text {
+                                text: "hey ".into(),
+                                style: None
+                            },
+                            RichTextSectionElement::User {
+                                user_id: "U0103ED6A22".into(),
+                                style: None
+                            },
+                            RichTextSectionElement::Text {
+                                text: " look at ".into(),
+                                style: Some(TextStyle {
+                                    bold: true,
+                                    ..Default::default()
+                                })
+                            },
+                            RichTextSectionElement::Link {
+                                url: "https://api.slack.com".into(),
+                                text: Some("the docs".into()),
+                                style: None
+                            },
+                            RichTextSectionElement::Text {
+                                text: " in ".into(),
+                                style: None
+                            },
+                            RichTextSectionElement::Channel {
+                                channel_id: "C0103EVPKTQ".into(),
+                                style: None
+                            },
+                            RichTextSectionElement::Usergroup {
+                                usergroup_id: "S0614TZR7".into(),
+                                style: None
+                            },
+                            RichTextSectionElement::Broadcast {
+                                range: BroadcastRange::Here
+                            },
+                            RichTextSectionElement::Emoji {
+                                name: "thumbsup".into(),
+                                unicode: Some("1f44d".into()),
+                                skin_tone: Some(2)
+                            },
+                        ]
+                    },
+                    RichTextElement::RichTextList {
+                        elements: vec![RichTextElement::RichTextSection {
+                            elements: vec![RichTextSectionElement::Text {
+                                text: "first".into(),
+                                style: Some(TextStyle {
+                                    italic: true,
+                                    strike: true,
+                                    ..Default::default()
+                                })
+                            }]
+                        }],
+                        style: RichTextListStyle::Ordered,
+                        indent: 1,
+                        offset: 0,
+                        border: 0
+                    },
+                    RichTextElement::RichTextPreformatted {
+                        elements: vec![RichTextSectionElement::Text {
+                            text: "let x = 1;".into(),
+                            style: None
+                        }],
+                        border: 0
+                    },
+                    RichTextElement::RichTextQuote {
+                        elements: vec![RichTextSectionElement::Text {
+                            text: "quoted".into(),
+                            style: Some(TextStyle {
+                                code: true,
+                                ..Default::default()
+                            })
+                        }],
+                        border: 0
+                    },
+                ]
+            }],
+            blocks
+        );
+    }
+
+    #[test]
+    fn unknown_inner_event_works() {
+        let callback = "{
+            \"token\":\"gDimgAnOYefZ58jniKrv8BNA\",
+            \"team_id\":\"T010346TVPH\",
+            \"api_app_id\":\"A0103EF7Y3G\",
+            \"event\":{
+                \"type\":\"workflow_step_execute\",
+                \"callback_id\":\"open_ticket\"
+            },
+            \"type\":\"event_callback\",
+            \"event_id\":\"Ev0103PNN1L7\",
+            \"event_time\":1584339455
+        }";
+        let callback: OuterEvent = serde_json::from_str(callback).unwrap();
+        match callback {
+            OuterEvent::EventCallback {
+                event: InnerEvent::Unknown { r#type, raw },
+                ..
+            } => {
+                assert_eq!("workflow_step_execute", r#type);
+                assert_eq!("open_ticket", raw["callback_id"]);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn unknown_outer_event_works() {
+        let event: OuterEvent =
+            serde_json::from_str("{\"type\":\"brand_new_thing\",\"token\":\"XXYYZZ\"}").unwrap();
+        match event {
+            OuterEvent::Unknown { r#type, raw } => {
+                assert_eq!("brand_new_thing", r#type);
+                assert_eq!("XXYYZZ", raw["token"]);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn malformed_known_event_is_an_error() {
+        let callback = "{
+            \"token\":\"gDimgAnOYefZ58jniKrv8BNA\",
+            \"team_id\":\"T010346TVPH\",
+            \"api_app_id\":\"A0103EF7Y3G\",
+            \"event\":{
+                \"type\":\"app_home_opened\",
+                \"channel\":\"D0LAN2Q65\"
+            },
+            \"type\":\"event_callback\",
+            \"event_id\":\"Ev0103PNN1L7\",
+            \"event_time\":1584339455
+        }";
+        let err = serde_json::from_str::<OuterEvent>(callback).unwrap_err();
+        assert!(err.to_string().contains("missing field `user`"), "{}", err);
+    }
+
+    #[test]
+    fn missing_type_is_an_error() {
+        assert!(serde_json::from_str::<OuterEvent>("{\"token\":\"XXYYZZ\"}").is_err());
+    }
+
+    /// Catches the variants the derived deserializers expect, via `unknown_variant`.
+    #[derive(Debug, Default)]
+    struct Expected(Vec<&'static str>);
+
+    impl std::fmt::Display for Expected {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+
+    impl std::error::Error for Expected {}
+
+    impl serde::de::Error for Expected {
+        fn custom<T: std::fmt::Display>(_: T) -> Self {
+            Expected::default()
+        }
+
+        fn unknown_variant(_: &str, expected: &'static [&'static str]) -> Self {
+            Expected(expected.to_vec())
+        }
+    }
+
+    type Tagged = MapDeserializer<'static, IntoIter<(&'static str, &'static str)>, Expected>;
+
+    fn expected<T, F>(tag: &'static str, derived: F) -> Vec<&'static str>
+    where
+        F: FnOnce(Tagged) -> Result<T, Expected>,
+    {
+        let fields = vec![(tag, "not_a_real_tag")].into_iter();
+        let mut expected = match derived(MapDeserializer::new(fields)) {
+            Ok(_) => panic!("not_a_real_tag deserialized"),
+            Err(Expected(expected)) => expected,
+        };
+        expected.sort();
+        expected
+    }
+
+    fn sorted(types: &[&'static str]) -> Vec<&'static str> {
+        let mut types = types.to_vec();
+        types.sort();
+        types
+    }
+
+    #[test]
+    fn known_types_match_the_derived_deserializers() {
+        assert_eq!(
+            sorted(OuterEvent::TYPES),
+            expected("type", OuterEvent::deserialize)
+        );
+        assert_eq!(
+            sorted(InnerEvent::TYPES),
+            expected("type", InnerEvent::deserialize)
+        );
+        assert_eq!(
+            sorted(message::MessageSubtype::SUBTYPES),
+            expected("subtype", message::MessageSubtype::deserialize)
+        );
+        assert_eq!(
+            sorted(crate::interactions::Interaction::TYPES),
+            expected("type", crate::interactions::Interaction::deserialize)
+        );
+    }
+
+    fn ts(raw: &str) -> Ts {
+        raw.parse().unwrap()
+    }
+
+    fn inner_event(event: &str) -> InnerEvent {
+        let callback = format!(
+            r#"{{
+                "token": "XXYYZZ",
+                "team_id": "TXXXXXXXX",
+                "api_app_id": "AXXXXXXXXX",
+                "event": {},
+                "type": "event_callback",
+                "event_id": "Ev08MFMKH6",
+                "event_time": 1234567890
+            }}"#,
+            event
+        );
+        match serde_json::from_str::<OuterEvent>(&callback).unwrap() {
+            OuterEvent::EventCallback { event, .. } => event,
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn app_requested_works() {
+        let event = inner_event(
+            r#"{
+                "type": "app_requested",
+                "app_request": {
+                    "id": "1234",
+                    "app": {
+                        "id": "A5678",
+                        "name": "Brent's app",
+                        "description": "They're good apps, Bront.",
+                        "help_url": "brontsapp.com",
+                        "privacy_policy_url": "brontsapp.com",
+                        "app_homepage_url": "brontsapp.com",
+                        "app_directory_url": "https://slack.slack.com/apps/A102ARD7Y",
+                        "is_app_directory_approved": true,
+                        "is_internal": false,
+                        "additional_info": "none"
+                    },
+                    "previous_resolution": null,
+                    "user": {"id": "U1234", "name": "Bront", "email": "bront@brent.com"},
+                    "team": {"id": "T1234", "name": "Brant App Team", "domain": "brantappteam"},
+                    "enterprise": null,
+                    "scopes": [{
+                        "name": "app_configurations:read",
+                        "description": "Read app configuration info via App Manifest APIs",
+                        "is_sensitive": false,
+                        "token_type": "user"
+                    }],
+                    "message": "none",
+                    "date_created": 1613765316
+                }
+            }"#,
+        );
+        match event {
+            InnerEvent::AppRequested { app_request } => {
+                assert_eq!("1234", app_request.id);
+                assert_eq!("A5678", app_request.app.id);
+                assert!(app_request.app.is_app_directory_approved);
+                assert_eq!("U1234", app_request.user.id);
+                assert_eq!("brantappteam", app_request.team.domain);
+                assert_eq!("app_configurations:read", app_request.scopes[0].name);
+                assert_eq!(None, app_request.previous_resolution);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn app_uninstalled_works() {
+        assert_eq!(
+            InnerEvent::AppUninstalled {},
+            inner_event(r#"{"type": "app_uninstalled"}"#)
+        );
+    }
+
+    #[test]
+    fn call_rejected_works() {
+        assert_eq!(
+            InnerEvent::CallRejected {
+                call_id: "R0E69JAIF".into(),
+                user_id: "U0E7E3SA9".into(),
+                channel_id: "DUX4YKDA7".into(),
+                external_unique_id: "123-456-7890".into()
+            },
+            inner_event(
+                r#"{
+                    "type": "call_rejected",
+                    "call_id": "R0E69JAIF",
+                    "user_id": "U0E7E3SA9",
+                    "channel_id": "DUX4YKDA7",
+                    "external_unique_id": "123-456-7890"
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn channel_created_works() {
+        assert_eq!(
+            InnerEvent::ChannelCreated {
+                channel: Channel {
+                    id: "C024BE91L".into(),
+                    name: "fun".into(),
+                    created: 1_360_782_804,
+                    creator: "U024BE7LH".into()
+                }
+            },
+            inner_event(
+                r#"{
+                    "type": "channel_created",
+                    "channel": {
+                        "id": "C024BE91L",
+                        "name": "fun",
+                        "created": 1360782804,
+                        "creator": "U024BE7LH"
+                    }
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn channel_deleted_works() {
+        assert_eq!(
+            InnerEvent::ChannelDeleted {
+                channel: "C024BE91L".into()
+            },
+            inner_event(r#"{"type": "channel_deleted", "channel": "C024BE91L"}"#)
+        );
+    }
+
+    #[test]
+    fn channel_history_changed_works() {
+        assert_eq!(
+            InnerEvent::ChannelHistoryChanged {
+                latest: ts("1358877455.000010"),
+                ts: ts("1361482916.000003"),
+                event_ts: ts("1361482916.000004")
+            },
+            inner_event(
+                r#"{
+                    "type": "channel_history_changed",
+                    "latest": "1358877455.000010",
+                    "ts": "1361482916.000003",
+                    "event_ts": "1361482916.000004"
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn channel_left_works() {
+        assert_eq!(
+            InnerEvent::ChannelLeft {
+                channel: "C024BE91L".into()
+            },
+            inner_event(r#"{"type": "channel_left", "channel": "C024BE91L"}"#)
+        );
+    }
+
+    #[test]
+    fn channel_rename_works() {
+        assert_eq!(
+            InnerEvent::ChannelRename {
+                channel: RenamedChannel {
+                    id: "C02ELGNBH".into(),
+                    name: "new_name".into(),
+                    created: 1_360_782_804
+                }
+            },
+            inner_event(
+                r#"{
+                    "type": "channel_rename",
+                    "channel": {"id": "C02ELGNBH", "name": "new_name", "created": 1360782804}
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn channel_shared_works() {
+        assert_eq!(
+            InnerEvent::ChannelShared {
+                connected_team_id: "E163Q94DX".into(),
+                channel: "C123ABC456".into(),
+                event_ts: ts("1561064063.001100")
+            },
+            inner_event(
+                r#"{
+                    "type": "channel_shared",
+                    "connected_team_id": "E163Q94DX",
+                    "channel": "C123ABC456",
+                    "event_ts": "1561064063.001100"
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn channel_unarchive_works() {
+        assert_eq!(
+            InnerEvent::ChannelUnarchive {
+                channel: "C024BE91L".into(),
+                user: "U024BE7LH".into()
+            },
+            inner_event(
+                r#"{"type": "channel_unarchive", "channel": "C024BE91L", "user": "U024BE7LH"}"#
+            )
+        );
+    }
+
+    #[test]
+    fn channel_unshared_works() {
+        assert_eq!(
+            InnerEvent::ChannelUnshared {
+                previously_connected_team_id: "E163Q94DX".into(),
+                channel: "C123ABC456".into(),
+                is_ext_shared: false,
+                event_ts: ts("1561064063.001100")
+            },
+            inner_event(
+                r#"{
+                    "type": "channel_unshared",
+                    "previously_connected_team_id": "E163Q94DX",
+                    "channel": "C123ABC456",
+                    "is_ext_shared": false,
+                    "event_ts": "1561064063.001100"
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn dnd_updated_works() {
+        assert_eq!(
+            InnerEvent::DndUpdated {
+                user: "U1234".into(),
+                dnd_status: DndStatus {
+                    dnd_enabled: true,
+                    next_dnd_start_ts: 1_450_387_800,
+                    next_dnd_end_ts: 1_450_423_800,
+                    snooze_enabled: Some(true),
+                    snooze_endtime: Some(1_450_373_897)
+                }
+            },
+            inner_event(
+                r#"{
+                    "type": "dnd_updated",
+                    "user": "U1234",
+                    "dnd_status": {
+                        "dnd_enabled": true,
+                        "next_dnd_start_ts": 1450387800,
+                        "next_dnd_end_ts": 1450423800,
+                        "snooze_enabled": true,
+                        "snooze_endtime": 1450373897
+                    }
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn dnd_updated_user_works() {
+        assert_eq!(
+            InnerEvent::DndUpdatedUser {
+                user: "U1234".into(),
+                dnd_status: DndStatus {
+                    dnd_enabled: true,
+                    next_dnd_start_ts: 1_450_387_800,
+                    next_dnd_end_ts: 1_450_423_800,
+                    snooze_enabled: None,
+                    snooze_endtime: None
+                }
+            },
+            inner_event(
+                r#"{
+                    "type": "dnd_updated_user",
+                    "user": "U1234",
+                    "dnd_status": {
+                        "dnd_enabled": true,
+                        "next_dnd_start_ts": 1450387800,
+                        "next_dnd_end_ts": 1450423800
+                    }
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn email_domain_changed_works() {
+        assert_eq!(
+            InnerEvent::EmailDomainChanged {
+                email_domain: "example.com".into(),
+                event_ts: ts("1234356.1231")
+            },
+            inner_event(
+                r#"{"type": "email_domain_changed", "email_domain": "example.com", "event_ts": "1234356.1231"}"#
+            )
+        );
+    }
+
+    #[test]
+    fn emoji_changed_works() {
+        assert_eq!(
+            InnerEvent::EmojiChanged {
+                change: EmojiChange::Add {
+                    name: "picard_facepalm".into(),
+                    value: "https://my.slack.com/emoji/picard_facepalm/db8e287430eaa459.gif".into()
+                },
+                event_ts: ts("1361482916.000004")
+            },
+            inner_event(
+                r#"{
+                    "type": "emoji_changed",
+                    "subtype": "add",
+                    "name": "picard_facepalm",
+                    "value": "https://my.slack.com/emoji/picard_facepalm/db8e287430eaa459.gif",
+                    "event_ts": "1361482916.000004"
+                }"#
+            )
+        );
+        assert_eq!(
+            InnerEvent::EmojiChanged {
+                change: EmojiChange::Remove {
+                    names: vec!["picard_facepalm".into()]
+                },
+                event_ts: ts("1361482916.000004")
+            },
+            inner_event(
+                r#"{
+                    "type": "emoji_changed",
+                    "subtype": "remove",
+                    "names": ["picard_facepalm"],
+                    "event_ts": "1361482916.000004"
+                }"#
+            )
+        );
+        assert_eq!(
+            InnerEvent::EmojiChanged {
+                change: EmojiChange::Rename {
+                    old_name: "picard_facepalm".into(),
+                    new_name: "captain_picard_facepalm".into(),
+                    value: "https://my.slack.com/emoji/picard_facepalm/db8e287430eaa459.gif".into()
+                },
+                event_ts: ts("1361482916.000004")
+            },
+            inner_event(
+                r#"{
+                    "type": "emoji_changed",
+                    "subtype": "rename",
+                    "old_name": "picard_facepalm",
+                    "new_name": "captain_picard_facepalm",
+                    "value": "https://my.slack.com/emoji/picard_facepalm/db8e287430eaa459.gif",
+                    "event_ts": "1361482916.000004"
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn file_change_works() {
+        assert_eq!(
+            InnerEvent::FileChange {
+                file_id: "F2147483862".into(),
+                file: FileRef {
+                    id: "F2147483862".into()
+                }
+            },
+            inner_event(
+                r#"{"type": "file_change", "file_id": "F2147483862", "file": {"id": "F2147483862"}}"#
+            )
+        );
+    }
+
+    #[test]
+    fn file_comment_added_works() {
+        match inner_event(
+            r#"{
+                "type": "file_comment_added",
+                "comment": {"id": "Fc67890", "comment": "nice"},
+                "file_id": "F2147483862",
+                "file": {"id": "F2147483862"}
+            }"#,
+        ) {
+            InnerEvent::FileCommentAdded {
+                comment,
+                file_id,
+                file,
+            } => {
+                assert_eq!("Fc67890", comment["id"]);
+                assert_eq!("F2147483862", file_id);
+                assert_eq!("F2147483862", file.id);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn file_comment_deleted_works() {
+        assert_eq!(
+            InnerEvent::FileCommentDeleted {
+                comment: "Fc67890".into(),
+                file_id: "F2147483862".into(),
+                file: FileRef {
+                    id: "F2147483862".into()
+                }
+            },
+            inner_event(
+                r#"{
+                    "type": "file_comment_deleted",
+                    "comment": "Fc67890",
+                    "file_id": "F2147483862",
+                    "file": {"id": "F2147483862"}
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn file_comment_edited_works() {
+        match inner_event(
+            r#"{
+                "type": "file_comment_edited",
+                "comment": {"id": "Fc67890", "comment": "nicer"},
+                "file_id": "F2147483862",
+                "file": {"id": "F2147483862"}
+            }"#,
+        ) {
+            InnerEvent::FileCommentEdited { comment, .. } => {
+                assert_eq!("nicer", comment["comment"]);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn file_created_works() {
+        assert_eq!(
+            InnerEvent::FileCreated {
+                file_id: "F2147483862".into(),
+                file: FileRef {
+                    id: "F2147483862".into()
+                }
+            },
+            inner_event(
+                r#"{"type": "file_created", "file_id": "F2147483862", "file": {"id": "F2147483862"}}"#
+            )
+        );
+    }
+
+    #[test]
+    fn file_deleted_works() {
+        assert_eq!(
+            InnerEvent::FileDeleted {
+                file_id: "F2147483862".into(),
+                event_ts: ts("1361482916.000004")
+            },
+            inner_event(
+                r#"{"type": "file_deleted", "file_id": "F2147483862", "event_ts": "1361482916.000004"}"#
+            )
+        );
+    }
+
+    #[test]
+    fn file_public_works() {
+        assert_eq!(
+            InnerEvent::FilePublic {
+                file_id: "F2147483862".into(),
+                file: FileRef {
+                    id: "F2147483862".into()
+                }
+            },
+            inner_event(
+                r#"{"type": "file_public", "file_id": "F2147483862", "file": {"id": "F2147483862"}}"#
+            )
+        );
+    }
+
+    #[test]
+    fn file_shared_works() {
+        assert_eq!(
+            InnerEvent::FileShared {
+                file_id: "F2147483862".into(),
+                user_id: "U123ABC456".into(),
+                file: FileRef {
+                    id: "F2147483862".into()
+                },
+                channel_id: Some("C123ABC456".into()),
+                event_ts: ts("1361482916.000004")
+            },
+            inner_event(
+                r#"{
+                    "type": "file_shared",
+                    "channel_id": "C123ABC456",
+                    "file_id": "F2147483862",
+                    "user_id": "U123ABC456",
+                    "file": {"id": "F2147483862"},
+                    "event_ts": "1361482916.000004"
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn file_unshared_works() {
+        assert_eq!(
+            InnerEvent::FileUnshared {
+                file_id: "F2147483862".into(),
+                file: FileRef {
+                    id: "F2147483862".into()
+                }
+            },
+            inner_event(
+                r#"{"type": "file_unshared", "file_id": "F2147483862", "file": {"id": "F2147483862"}}"#
+            )
+        );
+    }
+
+    #[test]
+    fn grid_migration_works() {
+        assert_eq!(
+            InnerEvent::GridMigrationStarted {
+                enterprise_id: "EXXXXXXXX".into()
+            },
+            inner_event(r#"{"type": "grid_migration_started", "enterprise_id": "EXXXXXXXX"}"#)
+        );
+        assert_eq!(
+            InnerEvent::GridMigrationFinished {
+                enterprise_id: "EXXXXXXXX".into()
+            },
+            inner_event(r#"{"type": "grid_migration_finished", "enterprise_id": "EXXXXXXXX"}"#)
+        );
+    }
+
+    #[test]
+    fn group_archive_works() {
+        assert_eq!(
+            InnerEvent::GroupArchive {
+                channel: "G024BE91L".into()
+            },
+            inner_event(r#"{"type": "group_archive", "channel": "G024BE91L"}"#)
+        );
+    }
+
+    #[test]
+    fn group_close_works() {
+        assert_eq!(
+            InnerEvent::GroupClose {
+                user: "U024BE7LH".into(),
+                channel: "G024BE91L".into()
+            },
+            inner_event(r#"{"type": "group_close", "user": "U024BE7LH", "channel": "G024BE91L"}"#)
+        );
+    }
+
+    #[test]
+    fn group_deleted_works() {
+        assert_eq!(
+            InnerEvent::GroupDeleted {
+                channel: "G0QN9RGTT".into()
+            },
+            inner_event(r#"{"type": "group_deleted", "channel": "G0QN9RGTT"}"#)
+        );
+    }
+
+    #[test]
+    fn group_history_changed_works() {
+        assert_eq!(
+            InnerEvent::GroupHistoryChanged {
+                latest: ts("1358877455.000010"),
+                ts: ts("1361482916.000003"),
+                event_ts: ts("1361482916.000004")
+            },
+            inner_event(
+                r#"{
+                    "type": "group_history_changed",
+                    "latest": "1358877455.000010",
+                    "ts": "1361482916.000003",
+                    "event_ts": "1361482916.000004"
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn group_left_works() {
+        assert_eq!(
+            InnerEvent::GroupLeft {
+                channel: "G02ELGNBH".into()
+            },
+            inner_event(r#"{"type": "group_left", "channel": "G02ELGNBH"}"#)
+        );
+    }
+
+    #[test]
+    fn group_open_works() {
+        assert_eq!(
+            InnerEvent::GroupOpen {
+                user: "U024BE7LH".into(),
+                channel: "G024BE91L".into()
+            },
+            inner_event(r#"{"type": "group_open", "user": "U024BE7LH", "channel": "G024BE91L"}"#)
+        );
+    }
+
+    #[test]
+    fn group_rename_works() {
+        assert_eq!(
+            InnerEvent::GroupRename {
+                channel: RenamedChannel {
+                    id: "G02ELGNBH".into(),
+                    name: "new_name".into(),
+                    created: 1_360_782_804
+                }
+            },
+            inner_event(
+                r#"{
+                    "type": "group_rename",
+                    "channel": {"id": "G02ELGNBH", "name": "new_name", "created": 1360782804}
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn group_unarchive_works() {
+        assert_eq!(
+            InnerEvent::GroupUnarchive {
+                channel: "G024BE91L".into()
+            },
+            inner_event(r#"{"type": "group_unarchive", "channel": "G024BE91L"}"#)
+        );
+    }
+
+    #[test]
+    fn im_close_works() {
+        assert_eq!(
+            InnerEvent::ImClose {
+                user: "U024BE7LH".into(),
+                channel: "D024BE91L".into()
+            },
+            inner_event(r#"{"type": "im_close", "user": "U024BE7LH", "channel": "D024BE91L"}"#)
+        );
+    }
+
+    #[test]
+    fn im_created_works() {
+        assert_eq!(
+            InnerEvent::ImCreated {
+                user: "U024BE7LH".into(),
+                channel: ImChannel {
+                    id: "D024BE91L".into(),
+                    created: Some(1_360_782_804),
+                    user: Some("U024BE7LV".into())
+                }
+            },
+            inner_event(
+                r#"{
+                    "type": "im_created",
+                    "user": "U024BE7LH",
+                    "channel": {"id": "D024BE91L", "is_im": true, "created": 1360782804, "user": "U024BE7LV"}
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn im_history_changed_works() {
+        assert_eq!(
+            InnerEvent::ImHistoryChanged {
+                latest: ts("1358877455.000010"),
+                ts: ts("1361482916.000003"),
+                event_ts: ts("1361482916.000004")
+            },
+            inner_event(
+                r#"{
+                    "type": "im_history_changed",
+                    "latest": "1358877455.000010",
+                    "ts": "1361482916.000003",
+                    "event_ts": "1361482916.000004"
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn im_open_works() {
+        assert_eq!(
+            InnerEvent::ImOpen {
+                user: "U024BE7LH".into(),
+                channel: "D024BE91L".into()
+            },
+            inner_event(r#"{"type": "im_open", "user": "U024BE7LH", "channel": "D024BE91L"}"#)
+        );
+    }
+
+    #[test]
+    fn invite_requested_works() {
+        match inner_event(
+            r#"{
+                "type": "invite_requested",
+                "invite_request": {
+                    "id": "12345",
+                    "email": "bront@puppies.com",
+                    "date_created": 123455,
+                    "requester_ids": ["U123ABC456"],
+                    "channel_ids": ["C123ABC456"],
+                    "invite_type": "full_member",
+                    "real_name": "Brent",
+                    "date_expire": 123456,
+                    "request_reason": "They're good dogs, Brant",
+                    "team": {"id": "T12345", "name": "Puppy ratings workspace incorporated", "domain": "puppiesrus"}
+                }
+            }"#,
+        ) {
+            InnerEvent::InviteRequested { invite_request } => {
+                assert_eq!("bront@puppies.com", invite_request.email);
+                assert_eq!(
+                    vec![UserId::from("U123ABC456")],
+                    invite_request.requester_ids
+                );
+                assert_eq!("full_member", invite_request.invite_type);
+                assert_eq!("T12345", invite_request.team.id);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn link_shared_works() {
+        assert_eq!(
+            InnerEvent::LinkShared {
+                channel: "Cxxxxxx".into(),
+                user: "Uxxxxxxx".into(),
+                message_ts: ts("123456789.9875"),
+                thread_ts: Some(ts("123456621.1855")),
+                links: vec![
+                    SharedLink {
+                        domain: "example.com".into(),
+                        url: "https://example.com/12345".into()
+                    },
+                    SharedLink {
+                        domain: "another-example.com".into(),
+                        url: "https://yet.another-example.com/v/abcde".into()
+                    }
+                ]
+            },
+            inner_event(
+                r#"{
+                    "type": "link_shared",
+                    "channel": "Cxxxxxx",
+                    "user": "Uxxxxxxx",
+                    "message_ts": "123456789.9875",
+                    "thread_ts": "123456621.1855",
+                    "links": [
+                        {"domain": "example.com", "url": "https://example.com/12345"},
+                        {"domain": "another-example.com", "url": "https://yet.another-example.com/v/abcde"}
+                    ]
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn member_joined_channel_works() {
+        assert_eq!(
+            InnerEvent::MemberJoinedChannel {
+                user: "W06GH7XHN".into(),
+                channel: "C0698JE0H".into(),
+                channel_type: "C".into(),
+                team: "T024BE7LD".into(),
+                inviter: Some("U123456789".into())
+            },
+            inner_event(
+                r#"{
+                    "type": "member_joined_channel",
+                    "user": "W06GH7XHN",
+                    "channel": "C0698JE0H",
+                    "channel_type": "C",
+                    "team": "T024BE7LD",
+                    "inviter": "U123456789"
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn member_left_channel_works() {
+        assert_eq!(
+            InnerEvent::MemberLeftChannel {
+                user: "W06GH7XHN".into(),
+                channel: "C0698JE0H".into(),
+                channel_type: "C".into(),
+                team: "T024BE7LD".into()
+            },
+            inner_event(
+                r#"{
+                    "type": "member_left_channel",
+                    "user": "W06GH7XHN",
+                    "channel": "C0698JE0H",
+                    "channel_type": "C",
+                    "team": "T024BE7LD"
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn pin_added_works() {
+        match inner_event(
+            r#"{
+                "type": "pin_added",
+                "user": "U024BE7LH",
+                "channel_id": "C02ELGNBH",
+                "item": {
+                    "type": "message",
+                    "channel": "C02ELGNBH",
+                    "message": {"type": "message", "text": "pin me", "ts": "1360782400.498405"}
+                },
+                "event_ts": "1360782804.083113"
+            }"#,
+        ) {
+            InnerEvent::PinAdded {
+                user,
+                channel_id,
+                item: Some(SavedItem::Message { channel, message }),
+                event_ts,
+            } => {
+                assert_eq!("U024BE7LH", user);
+                assert_eq!(Some("C02ELGNBH".into()), channel_id);
+                assert_eq!("C02ELGNBH", channel);
+                assert_eq!("pin me", message["text"]);
+                assert_eq!("1360782804.083113", event_ts);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn pin_removed_works() {
+        match inner_event(
+            r#"{
+                "type": "pin_removed",
+                "user": "U024BE7LH",
+                "channel_id": "C02ELGNBH",
+                "item": {"type": "file", "file": {"id": "F2147483862"}},
+                "has_pins": false,
+                "event_ts": "1360782804.083113"
+            }"#,
+        ) {
+            InnerEvent::PinRemoved {
+                item: Some(SavedItem::File { file }),
+                has_pins,
+                ..
+            } => {
+                assert_eq!("F2147483862", file["id"]);
+                assert_eq!(Some(false), has_pins);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn reaction_added_works() {
+        assert_eq!(
+            InnerEvent::ReactionAdded {
+                user: "U024BE7LH".into(),
+                reaction: "thumbsup".into(),
+                item_user: Some("U0G9QF9C6".into()),
+                item: Item::Message {
+                    channel: "C0G9QF9GZ".into(),
+                    ts: ts("1360782400.498405")
+                },
+                event_ts: ts("1360782804.083113")
+            },
+            inner_event(
+                r#"{
+                    "type": "reaction_added",
+                    "user": "U024BE7LH",
+                    "reaction": "thumbsup",
+                    "item_user": "U0G9QF9C6",
+                    "item": {"type": "message", "channel": "C0G9QF9GZ", "ts": "1360782400.498405"},
+                    "event_ts": "1360782804.083113"
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn reaction_removed_works() {
+        assert_eq!(
+            InnerEvent::ReactionRemoved {
+                user: "U024BE7LH".into(),
+                reaction: "thumbsup".into(),
+                item_user: None,
+                item: Item::File {
+                    file: "F0HS27V1Z".into()
+                },
+                event_ts: ts("1360782804.083113")
+            },
+            inner_event(
+                r#"{
+                    "type": "reaction_removed",
+                    "user": "U024BE7LH",
+                    "reaction": "thumbsup",
+                    "item": {"type": "file", "file": "F0HS27V1Z"},
+                    "event_ts": "1360782804.083113"
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn resources_added_works() {
+        assert_eq!(
+            InnerEvent::ResourcesAdded {
+                resources: vec![GrantedResource {
+                    resource: Resource {
+                        r#type: "im".into(),
+                        grant: Grant {
+                            r#type: "specific".into(),
+                            resource_id: Some("DXXXXXXXX".into())
+                        }
+                    },
+                    scopes: vec!["chat:write:user".into(), "im:read".into()]
+                }]
+            },
+            inner_event(
+                r#"{
+                    "type": "resources_added",
+                    "resources": [{
+                        "resource": {"type": "im", "grant": {"type": "specific", "resource_id": "DXXXXXXXX"}},
+                        "scopes": ["chat:write:user", "im:read"]
+                    }]
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn resources_removed_works() {
+        assert_eq!(
+            InnerEvent::ResourcesRemoved {
+                resources: vec![GrantedResource {
+                    resource: Resource {
+                        r#type: "channel".into(),
+                        grant: Grant {
+                            r#type: "wildcard".into(),
+                            resource_id: None
+                        }
+                    },
+                    scopes: vec!["channels:read".into()]
+                }]
+            },
+            inner_event(
+                r#"{
+                    "type": "resources_removed",
+                    "resources": [{
+                        "resource": {"type": "channel", "grant": {"type": "wildcard"}},
+                        "scopes": ["channels:read"]
+                    }]
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn scope_denied_works() {
+        assert_eq!(
+            InnerEvent::ScopeDenied {
+                scopes: vec!["files:read".into(), "files:write:user".into()],
+                trigger_id: "241582872337.47445629121.string".into()
+            },
+            inner_event(
+                r#"{
+                    "type": "scope_denied",
+                    "scopes": ["files:read", "files:write:user"],
+                    "trigger_id": "241582872337.47445629121.string"
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn scope_granted_works() {
+        assert_eq!(
+            InnerEvent::ScopeGranted {
+                scopes: vec!["files:read".into()],
+                trigger_id: "241582872337.47445629121.string".into()
+            },
+            inner_event(
+                r#"{
+                    "type": "scope_granted",
+                    "scopes": ["files:read"],
+                    "trigger_id": "241582872337.47445629121.string"
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn star_added_works() {
+        assert_eq!(
+            InnerEvent::StarAdded {
+                user: "U024BE7LH".into(),
+                item: SavedItem::Channel {
+                    channel: "C02ELGNBH".into()
+                },
+                event_ts: ts("1360782804.083113")
+            },
+            inner_event(
+                r#"{
+                    "type": "star_added",
+                    "user": "U024BE7LH",
+                    "item": {"type": "channel", "channel": "C02ELGNBH"},
+                    "event_ts": "1360782804.083113"
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn star_removed_works() {
+        assert_eq!(
+            InnerEvent::StarRemoved {
+                user: "U024BE7LH".into(),
+                item: SavedItem::Im {
+                    channel: "D024BE91L".into()
+                },
+                event_ts: ts("1360782804.083113")
+            },
+            inner_event(
+                r#"{
+                    "type": "star_removed",
+                    "user": "U024BE7LH",
+                    "item": {"type": "im", "channel": "D024BE91L"},
+                    "event_ts": "1360782804.083113"
+                }"#
+            )
+        );
+    }
+
+    const SUBTEAM: &str = r#"{
+        "id": "S0615G0KT",
+        "team_id": "T060RNRCH",
+        "is_usergroup": true,
+        "name": "Marketing Team",
+        "description": "Marketing gurus, PR experts and product advocates.",
+        "handle": "marketing-team",
+        "is_external": false,
+        "date_create": 1446746793,
+        "date_update": 1446746793,
+        "date_delete": 0,
+        "auto_type": null,
+        "created_by": "U060RNRCZ",
+        "updated_by": "U060RNRCZ",
+        "deleted_by": null,
+        "prefs": {"channels": [], "groups": []},
+        "users": ["U060RNRCZ", "U060ULRC0"],
+        "user_count": 2
+    }"#;
+
+    #[test]
+    fn subteam_created_works() {
+        match inner_event(&format!(
+            r#"{{"type": "subteam_created", "subteam": {}}}"#,
+            SUBTEAM
+        )) {
+            InnerEvent::SubteamCreated { subteam } => {
+                assert_eq!("S0615G0KT", subteam.id);
+                assert_eq!("marketing-team", subteam.handle);
+                assert_eq!(None, subteam.auto_type);
+                assert_eq!(
+                    Some(vec!["U060RNRCZ".into(), "U060ULRC0".into()]),
+                    subteam.users
+                );
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn subteam_members_changed_works() {
+        assert_eq!(
+            InnerEvent::SubteamMembersChanged {
+                subteam_id: "S0614TZR7".into(),
+                team_id: "T060RNRCH".into(),
+                date_previous_update: 1_446_670_362,
+                date_update: 1_492_906_952,
+                added_users: vec!["U060RNRCZ".into(), "U060ULRC0".into()],
+                added_users_count: 2,
+                removed_users: vec!["U06129G2V".into()],
+                removed_users_count: 1
+            },
+            inner_event(
+                r#"{
+                    "type": "subteam_members_changed",
+                    "subteam_id": "S0614TZR7",
+                    "team_id": "T060RNRCH",
+                    "date_previous_update": 1446670362,
+                    "date_update": 1492906952,
+                    "added_users": ["U060RNRCZ", "U060ULRC0"],
+                    "added_users_count": 2,
+                    "removed_users": ["U06129G2V"],
+                    "removed_users_count": 1
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn subteam_self_added_works() {
+        assert_eq!(
+            InnerEvent::SubteamSelfAdded {
+                subteam_id: "S0615G0KT".into()
+            },
+            inner_event(r#"{"type": "subteam_self_added", "subteam_id": "S0615G0KT"}"#)
+        );
+    }
+
+    #[test]
+    fn subteam_self_removed_works() {
+        assert_eq!(
+            InnerEvent::SubteamSelfRemoved {
+                subteam_id: "S0615G0KT".into()
+            },
+            inner_event(r#"{"type": "subteam_self_removed", "subteam_id": "S0615G0KT"}"#)
+        );
+    }
+
+    #[test]
+    fn subteam_updated_works() {
+        match inner_event(&format!(
+            r#"{{"type": "subteam_updated", "subteam": {}}}"#,
+            SUBTEAM
+        )) {
+            InnerEvent::SubteamUpdated { subteam } => {
+                assert_eq!("Marketing Team", subteam.name);
+                assert_eq!(Some("U060RNRCZ".into()), subteam.updated_by);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn team_domain_change_works() {
+        assert_eq!(
+            InnerEvent::TeamDomainChange {
+                url: "https://my.slack.com".into(),
+                domain: "my".into()
+            },
+            inner_event(
+                r#"{"type": "team_domain_change", "url": "https://my.slack.com", "domain": "my"}"#
+            )
+        );
+    }
+
+    const USER: &str = r#"{
+        "id": "W012A3CDE",
+        "team_id": "T012AB3C4",
+        "name": "spengler",
+        "deleted": false,
+        "color": "9f69e7",
+        "real_name": "Egon Spengler",
+        "tz": "America/Los_Angeles",
+        "tz_label": "Pacific Daylight Time",
+        "tz_offset": -25200,
+        "profile": {
+            "avatar_hash": "ge3b51ca72de",
+            "status_text": "Print is dead",
+            "status_emoji": ":books:",
+            "real_name": "Egon Spengler",
+            "display_name": "spengler",
+            "real_name_normalized": "Egon Spengler",
+            "display_name_normalized": "spengler",
+            "email": "spengler@ghostbusters.example.com",
+            "image_24": "https://.../avatar/e3b51ca72dee4ef87916ae2b9240df50.jpg",
+            "image_72": "https://.../avatar/e3b51ca72dee4ef87916ae2b9240df50.jpg",
+            "team": "T012AB3C4"
+        },
+        "is_admin": true,
+        "is_owner": false,
+        "is_primary_owner": false,
+        "is_restricted": false,
+        "is_ultra_restricted": false,
+        "is_bot": false,
+        "updated": 1502138686,
+        "is_app_user": false,
+        "has_2fa": false
+    }"#;
+
+    #[test]
+    fn team_join_works() {
+        match inner_event(&format!(r#"{{"type": "team_join", "user": {}}}"#, USER)) {
+            InnerEvent::TeamJoin { user } => {
+                assert_eq!("W012A3CDE", user.id);
+                assert_eq!(Some(-25200), user.tz_offset);
+                assert!(user.is_admin);
+                assert_eq!(Some("spengler".to_string()), user.profile.display_name);
+                assert_eq!("spengler", user.display_name());
+                assert_eq!(None, user.profile.image_512);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn team_rename_works() {
+        assert_eq!(
+            InnerEvent::TeamRename {
+                name: "New Team Name Inc.".into()
+            },
+            inner_event(r#"{"type": "team_rename", "name": "New Team Name Inc."}"#)
+        );
+    }
+
+    #[test]
+    fn tokens_revoked_works() {
+        assert_eq!(
+            InnerEvent::TokensRevoked {
+                tokens: RevokedTokens {
+                    oauth: vec!["UXXXXXXXX".into()],
+                    bot: vec!["UXXXXXXXX".into()]
+                }
+            },
+            inner_event(
+                r#"{"type": "tokens_revoked", "tokens": {"oauth": ["UXXXXXXXX"], "bot": ["UXXXXXXXX"]}}"#
+            )
+        );
+    }
+
+    #[test]
+    fn inner_url_verification_works() {
+        assert_eq!(
+            InnerEvent::UrlVerification {
+                token: "Jhj5dZrVaK7ZwHHjRyZWjbDl".into(),
+                challenge: "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P".into()
+            },
+            inner_event(
+                r#"{
+                    "type": "url_verification",
+                    "token": "Jhj5dZrVaK7ZwHHjRyZWjbDl",
+                    "challenge": "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P"
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn user_change_works() {
+        match inner_event(&format!(r#"{{"type": "user_change", "user": {}}}"#, USER)) {
+            InnerEvent::UserChange { user } => {
+                assert_eq!("spengler", user.name);
+                assert_eq!(Some("Print is dead".to_string()), user.profile.status_text);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn user_resource_denied_works() {
+        assert_eq!(
+            InnerEvent::UserResourceDenied {
+                user: "WXXXXXXXX".into(),
+                scopes: vec!["reminders:write:user".into(), "reminders:read:user".into()],
+                trigger_id: "27082968880.6048553856.5eb9c671f75c636135fdb6bb9e87b606".into()
+            },
+            inner_event(
+                r#"{
+                    "type": "user_resource_denied",
+                    "user": "WXXXXXXXX",
+                    "scopes": ["reminders:write:user", "reminders:read:user"],
+                    "trigger_id": "27082968880.6048553856.5eb9c671f75c636135fdb6bb9e87b606"
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn user_resource_granted_works() {
+        assert_eq!(
+            InnerEvent::UserResourceGranted {
+                user: "WXXXXXXXX".into(),
+                scopes: vec!["reminders:write:user".into()],
+                trigger_id: "27082968880.6048553856.5eb9c671f75c636135fdb6bb9e87b606".into()
+            },
+            inner_event(
+                r#"{
+                    "type": "user_resource_granted",
+                    "user": "WXXXXXXXX",
+                    "scopes": ["reminders:write:user"],
+                    "trigger_id": "27082968880.6048553856.5eb9c671f75c636135fdb6bb9e87b606"
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn user_resource_removed_works() {
+        assert_eq!(
+            InnerEvent::UserResourceRemoved {
+                user: "WXXXXXXXX".into(),
+                trigger_id: "27082968880.6048553856.5eb9c671f75c636135fdb6bb9e87b606".into()
+            },
+            inner_event(
+                r#"{
+                    "type": "user_resource_removed",
+                    "user": "WXXXXXXXX",
+                    "trigger_id": "27082968880.6048553856.5eb9c671f75c636135fdb6bb9e87b606"
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn threaded_reply_works() {
+        match inner_event(
+            r#"{
+                "type": "message",
+                "client_msg_id": "a5899740-233f-4656-8469-5f88c5b8db27",
+                "channel": "C123ABC456",
+                "channel_type": "channel",
+                "user": "U061F7AUR",
+                "text": "Was there was there?",
+                "ts": "1482960137.003543",
+                "event_ts": "1482960137.003543",
+                "thread_ts": "1482960100.003541",
+                "parent_user_id": "U0103ED6A22"
+            }"#,
+        ) {
+            InnerEvent::Message {
+                thread_ts,
+                parent_user_id,
+                subtype: MessageSubtype::Plain(message),
+                ..
+            } => {
+                assert_eq!(Some(ts("1482960100.003541")), thread_ts);
+                assert_eq!(Some("U0103ED6A22".into()), parent_user_id);
+                assert_eq!("Was there was there?", message.text);
+                assert!(message.blocks.is_empty());
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn bot_message_works() {
+        assert_eq!(
+            InnerEvent::Message {
+                ts: ts("1358877455.000010"),
+                channel: "D0103EVPKTQ".into(),
+                event_ts: ts("1358877455.000010"),
+                channel_type: "im".into(),
+                thread_ts: None,
+                parent_user_id: None,
+                subtype: MessageSubtype::BotMessage {
+                    bot_id: "BB12033".into(),
+                    user: None,
+                    username: Some("github".into()),
+                    text: "Pushing is the answer".into(),
+                    blocks: vec![MessageBlock::Layout(Block::Section(
+                        crate::blocks::Section::new(crate::blocks::Text::mrkdwn(
+                            "Pushing is the *answer*"
+                        ))
+                        .block_id("ab1")
+                    ))]
+                }
+            },
+            inner_event(
+                r#"{
+                    "type": "message",
+                    "subtype": "bot_message",
+                    "ts": "1358877455.000010",
+                    "channel": "D0103EVPKTQ",
+                    "channel_type": "im",
+                    "event_ts": "1358877455.000010",
+                    "text": "Pushing is the answer",
+                    "bot_id": "BB12033",
+                    "username": "github",
+                    "icons": {},
+                    "blocks": [{
+                        "type": "section",
+                        "block_id": "ab1",
+                        "text": {"type": "mrkdwn", "text": "Pushing is the *answer*"}
+                    }]
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn message_changed_works() {
+        match inner_event(
+            r#"{
+                "type": "message",
+                "subtype": "message_changed",
+                "hidden": true,
+                "channel": "C123ABC456",
+                "channel_type": "channel",
+                "ts": "1358878755.000001",
+                "event_ts": "1358878755.000001",
+                "message": {
+                    "type": "message",
+                    "user": "U123ABC456",
+                    "text": "Hello, world!",
+                    "ts": "1355517523.000005",
+                    "edited": {"user": "U123ABC456", "ts": "1358878755.000001"}
+                },
+                "previous_message": {
+                    "type": "message",
+                    "user": "U123ABC456",
+                    "text": "Hello, wrld!",
+                    "ts": "1355517523.000005"
+                }
+            }"#,
+        ) {
+            InnerEvent::Message {
+                subtype:
+                    MessageSubtype::MessageChanged {
+                        message,
+                        previous_message,
+                        hidden,
+                    },
+                ..
+            } => {
+                assert!(hidden);
+                assert_eq!("Hello, world!", message.text);
+                assert_eq!(
+                    Some(Edited {
+                        user: "U123ABC456".into(),
+                        ts: ts("1358878755.000001")
+                    }),
+                    message.edited
+                );
+                assert_eq!("Hello, wrld!", previous_message.text);
+                assert_eq!(None, previous_message.edited);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn message_deleted_works() {
+        match inner_event(
+            r#"{
+                "type": "message",
+                "subtype": "message_deleted",
+                "hidden": true,
+                "channel": "C123ABC456",
+                "channel_type": "channel",
+                "ts": "1358878755.000001",
+                "event_ts": "1358878755.000001",
+                "deleted_ts": "1358878749.000002",
+                "previous_message": {
+                    "type": "message",
+                    "user": "U123ABC456",
+                    "text": "Delete me",
+                    "ts": "1358878749.000002"
+                }
+            }"#,
+        ) {
+            InnerEvent::Message {
+                subtype:
+                    MessageSubtype::MessageDeleted {
+                        deleted_ts,
+                        previous_message,
+                        ..
+                    },
+                ..
+            } => {
+                assert_eq!("1358878749.000002", deleted_ts);
+                assert_eq!("Delete me", previous_message.unwrap().text);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn channel_join_works() {
+        assert_eq!(
+            InnerEvent::Message {
+                ts: ts("1358877458.000011"),
+                channel: "C123ABC456".into(),
+                event_ts: ts("1358877458.000011"),
+                channel_type: "channel".into(),
+                thread_ts: None,
+                parent_user_id: None,
+                subtype: MessageSubtype::ChannelJoin {
+                    user: "W123ABC456".into(),
+                    text: "<@W123ABC456|bobby> has joined the channel".into(),
+                    inviter: Some("U0103ED6A22".into())
+                }
+            },
+            inner_event(
+                r#"{
+                    "type": "message",
+                    "subtype": "channel_join",
+                    "ts": "1358877458.000011",
+                    "event_ts": "1358877458.000011",
+                    "channel": "C123ABC456",
+                    "channel_type": "channel",
+                    "user": "W123ABC456",
+                    "text": "<@W123ABC456|bobby> has joined the channel",
+                    "inviter": "U0103ED6A22"
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn thread_broadcast_works() {
+        match inner_event(
+            r#"{
+                "type": "message",
+                "subtype": "thread_broadcast",
+                "channel": "C123ABC456",
+                "channel_type": "channel",
+                "user": "U061F7AUR",
+                "text": "Was there was there?",
+                "ts": "1482960137.003543",
+                "event_ts": "1482960137.003543",
+                "thread_ts": "1482960100.003541",
+                "root": {
+                    "text": "Who was there?",
+                    "user": "U061F7AUR",
+                    "thread_ts": "1482960100.003541",
+                    "ts": "1482960100.003541"
+                }
+            }"#,
+        ) {
+            InnerEvent::Message {
+                thread_ts,
+                subtype: MessageSubtype::ThreadBroadcast { user, root, .. },
+                ..
+            } => {
+                assert_eq!(Some(ts("1482960100.003541")), thread_ts);
+                assert_eq!("U061F7AUR", user);
+                assert_eq!("Who was there?", root.unwrap().text);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn file_share_works() {
+        match inner_event(
+            r#"{
+                "type": "message",
+                "subtype": "file_share",
+                "channel": "D0103EVPKTQ",
+                "channel_type": "im",
+                "user": "U0103ED6A22",
+                "text": "here's the log",
+                "ts": "1584339455.000200",
+                "event_ts": "1584339455.000200",
+                "upload": true,
+                "files": [{"id": "F2147483862", "name": "echo.log"}]
+            }"#,
+        ) {
+            InnerEvent::Message {
+                subtype: MessageSubtype::FileShare { files, upload, .. },
+                ..
+            } => {
+                assert!(upload);
+                assert_eq!("F2147483862", files[0].id);
+                assert_eq!(Some("echo.log"), files[0].name.as_deref());
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn me_message_works() {
+        assert_eq!(
+            InnerEvent::Message {
+                ts: ts("1355517523.000005"),
+                channel: "C123ABC456".into(),
+                event_ts: ts("1355517523.000005"),
+                channel_type: "channel".into(),
+                thread_ts: None,
+                parent_user_id: None,
+                subtype: MessageSubtype::MeMessage {
+                    user: "U123ABC456".into(),
+                    text: "is doing that thing".into()
+                }
+            },
+            inner_event(
+                r#"{
+                    "type": "message",
+                    "subtype": "me_message",
+                    "channel": "C123ABC456",
+                    "channel_type": "channel",
+                    "user": "U123ABC456",
+                    "text": "is doing that thing",
+                    "ts": "1355517523.000005",
+                    "event_ts": "1355517523.000005"
+                }"#
+            )
+        );
+    }
+
+    #[test]
+    fn other_subtypes_keep_raw() {
+        match inner_event(
+            r#"{
+                "type": "message",
+                "subtype": "channel_topic",
+                "channel": "C123ABC456",
+                "channel_type": "channel",
+                "user": "U123ABC456",
+                "topic": "hello world",
+                "text": "<@U123ABC456|bob> set the channel topic: hello world",
+                "ts": "1358877455.000010",
+                "event_ts": "1358877455.000010"
+            }"#,
+        ) {
+            InnerEvent::Message {
+                subtype: MessageSubtype::Other { subtype, raw },
+                ..
+            } => {
+                assert_eq!("channel_topic", subtype);
+                assert_eq!("hello world", raw["topic"]);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
 }
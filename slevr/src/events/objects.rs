@@ -0,0 +1,315 @@
+//! The objects that show up inside event payloads.
+use crate::{AppId, ChannelId, TeamId, Ts, UserId};
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct Channel {
+    pub id: ChannelId,
+    pub name: String,
+    pub created: u64,
+    pub creator: UserId,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct RenamedChannel {
+    pub id: ChannelId,
+    pub name: String,
+    pub created: u64,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct ImChannel {
+    pub id: ChannelId,
+    pub created: Option<u64>,
+    /// The other half of the conversation
+    pub user: Option<UserId>,
+}
+
+/// Only the id is sent with file events, call files.info for the rest.
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct FileRef {
+    pub id: String,
+}
+
+/// A file, as files.info and file_share messages describe it. File events only carry a
+/// `FileRef`, so use files.info to get the rest. Which fields are set depends on the kind of
+/// file and who's asking.
+/// More info - https://api.slack.com/types/file
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct File {
+    pub id: String,
+    pub created: Option<u64>,
+    pub name: Option<String>,
+    pub title: Option<String>,
+    pub mimetype: Option<String>,
+    /// Slack's name for the type, e.g. `text` or `png`
+    pub filetype: Option<String>,
+    pub pretty_type: Option<String>,
+    /// Who uploaded it
+    pub user: Option<UserId>,
+    /// In bytes
+    pub size: Option<u64>,
+    /// hosted, external, snippet or post
+    pub mode: Option<String>,
+    #[serde(default)]
+    pub is_external: bool,
+    #[serde(default)]
+    pub is_public: bool,
+    /// Needs the bot token to fetch, see `SlackApiClient::download`.
+    pub url_private: Option<String>,
+    pub url_private_download: Option<String>,
+    pub permalink: Option<String>,
+    pub permalink_public: Option<String>,
+    /// Where it's been shared
+    #[serde(default)]
+    pub channels: Vec<ChannelId>,
+    #[serde(default)]
+    pub groups: Vec<ChannelId>,
+    #[serde(default)]
+    pub ims: Vec<ChannelId>,
+    /// The start of a snippet's text
+    pub preview: Option<String>,
+    /// How many lines a snippet has
+    pub lines: Option<u32>,
+}
+
+/// What a reaction was added to or removed from.
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Item {
+    Message { channel: ChannelId, ts: Ts },
+    File { file: String },
+    FileComment { file: String, file_comment: String },
+}
+
+/// What was pinned or starred. Unlike reaction items these carry the whole object.
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SavedItem {
+    Message { channel: ChannelId, message: Value },
+    File { file: Value },
+    FileComment { file: Value, comment: Value },
+    Channel { channel: ChannelId },
+    Im { channel: ChannelId },
+    Group { channel: ChannelId },
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct DndStatus {
+    pub dnd_enabled: bool,
+    pub next_dnd_start_ts: u64,
+    pub next_dnd_end_ts: u64,
+    /// Only sent to the user whose settings changed
+    pub snooze_enabled: Option<bool>,
+    pub snooze_endtime: Option<u64>,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(tag = "subtype", rename_all = "snake_case")]
+pub enum EmojiChange {
+    /// value is either an image url or `alias:<name>`
+    Add {
+        name: String,
+        value: String,
+    },
+    Remove {
+        names: Vec<String>,
+    },
+    Rename {
+        old_name: String,
+        new_name: String,
+        value: String,
+    },
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct SharedLink {
+    pub domain: String,
+    pub url: String,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct TeamRef {
+    pub id: TeamId,
+    pub name: String,
+    pub domain: String,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct AppRequest {
+    pub id: String,
+    pub app: RequestedApp,
+    pub user: RequestingUser,
+    pub team: TeamRef,
+    #[serde(default)]
+    pub scopes: Vec<RequestedScope>,
+    pub message: Option<String>,
+    pub date_created: u64,
+    pub previous_resolution: Option<Value>,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct RequestedApp {
+    pub id: AppId,
+    pub name: String,
+    pub description: Option<String>,
+    pub help_url: Option<String>,
+    pub privacy_policy_url: Option<String>,
+    pub app_homepage_url: Option<String>,
+    pub app_directory_url: Option<String>,
+    #[serde(default)]
+    pub is_app_directory_approved: bool,
+    #[serde(default)]
+    pub is_internal: bool,
+    pub additional_info: Option<String>,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct RequestingUser {
+    pub id: UserId,
+    pub name: String,
+    pub email: Option<String>,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct RequestedScope {
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub is_sensitive: bool,
+    pub token_type: Option<String>,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct InviteRequest {
+    pub id: String,
+    pub email: String,
+    pub date_created: u64,
+    #[serde(default)]
+    pub requester_ids: Vec<UserId>,
+    #[serde(default)]
+    pub channel_ids: Vec<ChannelId>,
+    /// full_member, restricted or ultra_restricted
+    pub invite_type: String,
+    pub real_name: Option<String>,
+    pub date_expire: Option<u64>,
+    pub request_reason: Option<String>,
+    pub team: TeamRef,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct GrantedResource {
+    pub resource: Resource,
+    #[serde(default)]
+    pub scopes: Vec<String>,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct Resource {
+    /// im, mpim, channel, group, ...
+    pub r#type: String,
+    pub grant: Grant,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct Grant {
+    /// specific or wildcard
+    pub r#type: String,
+    pub resource_id: Option<String>,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct Subteam {
+    pub id: String,
+    pub team_id: TeamId,
+    pub is_usergroup: bool,
+    pub name: String,
+    pub description: Option<String>,
+    pub handle: String,
+    #[serde(default)]
+    pub is_external: bool,
+    pub date_create: u64,
+    pub date_update: u64,
+    pub date_delete: u64,
+    pub auto_type: Option<String>,
+    pub created_by: UserId,
+    pub updated_by: Option<UserId>,
+    pub deleted_by: Option<UserId>,
+    /// Only included once the membership is known
+    pub users: Option<Vec<UserId>>,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct RevokedTokens {
+    #[serde(default)]
+    pub oauth: Vec<UserId>,
+    #[serde(default)]
+    pub bot: Vec<UserId>,
+}
+
+/// A workspace member, as sent with team_join and user_change.
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct User {
+    pub id: UserId,
+    pub team_id: Option<TeamId>,
+    pub name: String,
+    #[serde(default)]
+    pub deleted: bool,
+    pub real_name: Option<String>,
+    pub tz: Option<String>,
+    pub tz_label: Option<String>,
+    /// Seconds offset from UTC
+    pub tz_offset: Option<i32>,
+    /// Only set when asked for with include_locale
+    pub locale: Option<String>,
+    pub profile: UserProfile,
+    #[serde(default)]
+    pub is_admin: bool,
+    #[serde(default)]
+    pub is_owner: bool,
+    #[serde(default)]
+    pub is_primary_owner: bool,
+    #[serde(default)]
+    pub is_restricted: bool,
+    #[serde(default)]
+    pub is_ultra_restricted: bool,
+    #[serde(default)]
+    pub is_bot: bool,
+    #[serde(default)]
+    pub is_app_user: bool,
+    pub updated: Option<u64>,
+}
+
+impl User {
+    /// What Slack shows for them: their display name if they've set one, otherwise their
+    /// full name, otherwise their username.
+    pub fn display_name(&self) -> &str {
+        [&self.profile.display_name, &self.real_name]
+            .iter()
+            .filter_map(|name| name.as_deref())
+            .find(|name| !name.is_empty())
+            .unwrap_or(&self.name)
+    }
+}
+
+#[derive(Deserialize, Debug, PartialEq, Default)]
+#[serde(default)]
+pub struct UserProfile {
+    pub real_name: Option<String>,
+    pub display_name: Option<String>,
+    pub real_name_normalized: Option<String>,
+    pub display_name_normalized: Option<String>,
+    pub email: Option<String>,
+    pub title: Option<String>,
+    pub phone: Option<String>,
+    pub status_text: Option<String>,
+    pub status_emoji: Option<String>,
+    pub status_expiration: Option<u64>,
+    pub image_24: Option<String>,
+    pub image_48: Option<String>,
+    pub image_72: Option<String>,
+    pub image_192: Option<String>,
+    pub image_512: Option<String>,
+    pub team: Option<TeamId>,
+}
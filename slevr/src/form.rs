@@ -0,0 +1,188 @@
+//! `application/x-www-form-urlencoded` bodies, which is how Slack sends slash commands and
+//! interactivity payloads.
+use serde::de::DeserializeOwned;
+use serde::ser::Error as _;
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+
+pub const CONTENT_TYPE: &str = "application/x-www-form-urlencoded";
+
+#[derive(Debug)]
+pub enum FormError {
+    /// A `%` escape wasn't followed by two hex digits, or decoded to invalid UTF-8.
+    Encoding(String),
+    /// The fields decoded fine but didn't fit the type asked for.
+    Fields(serde_json::Error),
+}
+
+impl fmt::Display for FormError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormError::Encoding(value) => write!(f, "badly encoded form value {:?}", value),
+            FormError::Fields(err) => write!(f, "unexpected form fields: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for FormError {}
+
+fn unescape(value: &str) -> Result<String, FormError> {
+    let invalid = || FormError::Encoding(value.to_string());
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut rest = value.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        rest = tail;
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex = rest.get(..2).ok_or_else(invalid)?;
+                // from_str_radix would take a sign, so check the digits ourselves.
+                if !hex.iter().all(u8::is_ascii_hexdigit) {
+                    return Err(invalid());
+                }
+                let hex = std::str::from_utf8(hex).map_err(|_| invalid())?;
+                bytes.push(u8::from_str_radix(hex, 16).map_err(|_| invalid())?);
+                rest = &rest[2..];
+            }
+            byte => bytes.push(byte),
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| invalid())
+}
+
+/// The name and value pairs of a form body, in order.
+pub fn decode(body: &str) -> Result<Vec<(String, String)>, FormError> {
+    body.split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let mut parts = pair.splitn(2, '=');
+            let name = unescape(parts.next().unwrap_or_default())?;
+            let value = unescape(parts.next().unwrap_or_default())?;
+            Ok((name, value))
+        })
+        .collect()
+}
+
+/// Decodes a form body into a type with string fields. When a name appears more than once
+/// the last value wins.
+pub fn from_str<T: DeserializeOwned>(body: &str) -> Result<T, FormError> {
+    let fields = decode(body)?
+        .into_iter()
+        .map(|(name, value)| (name, serde_json::Value::String(value)))
+        .collect::<serde_json::Map<_, _>>();
+    serde_json::from_value(serde_json::Value::Object(fields)).map_err(FormError::Fields)
+}
+
+fn escape(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            byte => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+fn field_value(value: Value) -> String {
+    match value {
+        Value::String(value) => value,
+        Value::Array(values) => values
+            .into_iter()
+            .map(field_value)
+            .collect::<Vec<_>>()
+            .join(","),
+        value => value.to_string(),
+    }
+}
+
+/// The fields of a struct as name and value pairs, the way Slack's read methods and
+/// multipart uploads want their arguments. `None` fields are left out and lists are joined
+/// with commas.
+pub(crate) fn fields<T: Serialize>(value: &T) -> Result<Vec<(String, String)>, serde_json::Error> {
+    let fields = match serde_json::to_value(value)? {
+        Value::Object(fields) => fields,
+        _ => {
+            return Err(serde_json::Error::custom(
+                "only structs can be form encoded",
+            ))
+        }
+    };
+    Ok(fields
+        .into_iter()
+        .filter(|(_, value)| !value.is_null())
+        .map(|(name, value)| (name, field_value(value)))
+        .collect())
+}
+
+/// Encodes a struct as a form body, see `fields`.
+pub fn to_string<T: Serialize>(value: &T) -> Result<String, serde_json::Error> {
+    Ok(fields(value)?
+        .iter()
+        .map(|(name, value)| format!("{}={}", escape(name), escape(value)))
+        .collect::<Vec<_>>()
+        .join("&"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_works() {
+        assert_eq!(
+            vec![
+                ("text".to_string(), "94070 <@U123|bob> ✓".to_string()),
+                ("empty".to_string(), "".to_string()),
+                ("bare".to_string(), "".to_string()),
+                ("url".to_string(), "https://hooks.slack.com/a=b".to_string()),
+            ],
+            decode(
+                "text=94070+%3C%40U123%7Cbob%3E+%E2%9C%93&empty=&bare&url=https%3A%2F%2Fhooks.slack.com%2Fa%3Db"
+            )
+            .unwrap()
+        );
+        assert!(decode("text=%E2%9").is_err());
+        assert!(decode("text=%zz").is_err());
+        assert!(decode("text=%+1").is_err());
+        assert!(decode("text=%FF").is_err());
+    }
+
+    #[test]
+    fn to_string_works() {
+        #[derive(Serialize)]
+        struct List {
+            channel: String,
+            types: Vec<&'static str>,
+            limit: Option<u32>,
+            cursor: Option<String>,
+            inclusive: bool,
+        }
+        let body = to_string(&List {
+            channel: "C1234 & co".to_string(),
+            types: vec!["public_channel", "private_channel"],
+            limit: Some(200),
+            cursor: None,
+            inclusive: true,
+        })
+        .unwrap();
+        assert_eq!(
+            "channel=C1234%20%26%20co&inclusive=true&limit=200&types=public_channel%2Cprivate_channel",
+            body
+        );
+        assert_eq!(
+            vec![
+                ("channel".to_string(), "C1234 & co".to_string()),
+                ("inclusive".to_string(), "true".to_string()),
+                ("limit".to_string(), "200".to_string()),
+                (
+                    "types".to_string(),
+                    "public_channel,private_channel".to_string()
+                ),
+            ],
+            decode(&body).unwrap()
+        );
+    }
+}
@@ -0,0 +1,55 @@
+//! What point6 hands on once it has acknowledged a request. Event callbacks go exactly as
+//! Slack sent them, and everything else is wrapped with a `kind` so whoever receives it
+//! can tell what it's holding.
+use crate::commands::SlashCommand;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Forwarded {
+    SlashCommand(SlashCommand),
+    /// An interaction's `payload` as Slack sent it, read it with
+    /// [`Interaction`](crate::interactions::Interaction).
+    Interaction(Value),
+}
+
+impl Forwarded {
+    /// Whether `raw` was wrapped, rather than being an event callback.
+    pub fn is_wrapped(raw: &Value) -> bool {
+        raw.get("kind").is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn forwarded_is_tagged() {
+        let forwarded = Forwarded::SlashCommand(SlashCommand {
+            command: "/echo".to_string(),
+            text: "all".to_string(),
+            ..Default::default()
+        });
+        let raw = serde_json::to_value(&forwarded).unwrap();
+        assert_eq!(json!("slash_command"), raw["kind"]);
+        assert_eq!(json!("/echo"), raw["command"]);
+        assert!(Forwarded::is_wrapped(&raw));
+        assert_eq!(forwarded, Forwarded::deserialize(&raw).unwrap());
+
+        let payload = json!({"type": "block_actions", "trigger_id": "12466734323.1395872398"});
+        let raw = serde_json::to_value(Forwarded::Interaction(payload.clone())).unwrap();
+        assert_eq!(json!("interaction"), raw["kind"]);
+        assert_eq!(json!("block_actions"), raw["type"]);
+        assert_eq!(
+            Forwarded::Interaction(payload),
+            Forwarded::deserialize(&raw).unwrap()
+        );
+
+        assert!(!Forwarded::is_wrapped(
+            &json!({"type": "event_callback", "event": {}})
+        ));
+    }
+}
@@ -0,0 +1,97 @@
+//! Newtypes for the identifiers Slack hands out, so a channel can't be passed where a
+//! user was expected.
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+macro_rules! slack_id {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            pub fn new(id: impl Into<String>) -> Self {
+                $name(id.into())
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(id: String) -> Self {
+                $name(id)
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(id: &str) -> Self {
+                $name(id.to_string())
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialEq<$name> for &str {
+            fn eq(&self, other: &$name) -> bool {
+                *self == other.0
+            }
+        }
+    };
+}
+
+slack_id!(
+    /// A user, starting with U or W.
+    UserId
+);
+slack_id!(
+    /// A public or private channel, DM or group DM, starting with C, G or D.
+    /// The Web API also accepts channel names wherever it takes one of these.
+    ChannelId
+);
+slack_id!(
+    /// A workspace, starting with T.
+    TeamId
+);
+slack_id!(
+    /// A Slack app, starting with A.
+    AppId
+);
+slack_id!(
+    /// A bot, starting with B. Not the same as the bot's user id.
+    BotId
+);
+slack_id!(
+    /// A single Events API delivery, starting with Ev.
+    EventId
+);
@@ -0,0 +1,454 @@
+//! What Slack sends to your interactivity request URL when someone clicks a button,
+//! submits or closes a modal, or uses a shortcut. These arrive as a form with the JSON in
+//! its `payload` field.
+//! More info - https://api.slack.com/reference/interaction-payloads
+use crate::blocks::{OptionObject, Text};
+use crate::events::with_fallback;
+use crate::form::{self, FormError};
+use crate::{AppId, BotId, ChannelId, TeamId, Ts, UserId};
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A decoded `payload`, see `Interaction::from_form` for the request body.
+///
+/// `remote = "Self"` makes the inherent `Interaction::deserialize` the derived impl, call the
+/// `Deserialize` trait (or `serde_json::from_*`) to get the `Unknown` fallback.
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(remote = "Self", tag = "type", rename_all = "snake_case")]
+pub enum Interaction {
+    /// Someone used an interactive element in a message, modal or App Home.
+    BlockActions {
+        team: Team,
+        user: User,
+        api_app_id: AppId,
+        token: String,
+        /// Where the actions happened
+        container: Container,
+        /// Opens a modal, if used within 3 seconds.
+        trigger_id: String,
+        /// Set for actions in messages
+        channel: Option<Channel>,
+        message: Option<Value>,
+        /// Only for actions in messages, views use views.update instead.
+        response_url: Option<String>,
+        /// Set for actions in modals and App Home
+        view: Option<ViewPayload>,
+        actions: Vec<BlockAction>,
+    },
+    /// Someone submitted a modal. Answer with an empty 200 to close it.
+    ViewSubmission {
+        team: Team,
+        user: User,
+        api_app_id: AppId,
+        token: String,
+        trigger_id: String,
+        view: ViewPayload,
+        /// Only when the modal has an input block with `response_url_enabled`
+        #[serde(default)]
+        response_urls: Vec<ResponseUrl>,
+    },
+    /// Someone cancelled a modal that was opened with `notify_on_close`.
+    ViewClosed {
+        team: Team,
+        user: User,
+        api_app_id: AppId,
+        token: String,
+        view: ViewPayload,
+        /// The whole stack of views was closed, not just the top one.
+        is_cleared: bool,
+    },
+    /// A global shortcut, from the lightning bolt menu or search.
+    Shortcut {
+        team: Team,
+        user: User,
+        token: String,
+        callback_id: String,
+        trigger_id: String,
+        action_ts: Option<Ts>,
+    },
+    /// A message shortcut, from a message's context menu.
+    MessageAction {
+        team: Team,
+        user: User,
+        token: String,
+        callback_id: String,
+        trigger_id: String,
+        action_ts: Option<Ts>,
+        channel: Channel,
+        message: Value,
+        message_ts: Ts,
+        response_url: String,
+    },
+    /// Anything we couldn't parse, with the original payload.
+    #[serde(skip_deserializing)]
+    Unknown {
+        #[serde(skip)]
+        r#type: String,
+        #[serde(skip)]
+        raw: Value,
+    },
+}
+
+impl Interaction {
+    /// Every `type` the derived deserializer reads, anything else is Unknown.
+    pub(crate) const TYPES: &'static [&'static str] = &[
+        "block_actions",
+        "view_submission",
+        "view_closed",
+        "shortcut",
+        "message_action",
+    ];
+}
+
+impl<'de> Deserialize<'de> for Interaction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = Value::deserialize(deserializer)?;
+        with_fallback(
+            raw,
+            Interaction::TYPES,
+            |raw| Interaction::deserialize(raw),
+            |r#type, raw| Interaction::Unknown { r#type, raw },
+        )
+    }
+}
+
+#[derive(Deserialize)]
+struct Form {
+    payload: String,
+}
+
+impl Interaction {
+    /// Decodes the body of an interactivity request.
+    pub fn from_form(body: &str) -> Result<Self, FormError> {
+        let form: Form = form::from_str(body)?;
+        serde_json::from_str(&form.payload).map_err(FormError::Fields)
+    }
+
+    pub fn interaction_type(&self) -> &str {
+        match self {
+            Interaction::BlockActions { .. } => "block_actions",
+            Interaction::ViewSubmission { .. } => "view_submission",
+            Interaction::ViewClosed { .. } => "view_closed",
+            Interaction::Shortcut { .. } => "shortcut",
+            Interaction::MessageAction { .. } => "message_action",
+            Interaction::Unknown { r#type, .. } => r#type,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct Team {
+    pub id: TeamId,
+    pub domain: String,
+    pub enterprise_id: Option<String>,
+    pub enterprise_name: Option<String>,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct User {
+    pub id: UserId,
+    pub username: Option<String>,
+    pub name: Option<String>,
+    pub team_id: Option<TeamId>,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct Channel {
+    pub id: ChannelId,
+    pub name: String,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Container {
+    Message {
+        message_ts: Ts,
+        channel_id: ChannelId,
+        #[serde(default)]
+        is_ephemeral: bool,
+    },
+    View {
+        view_id: String,
+    },
+}
+
+/// One element someone used. Which of the `selected_*` fields is set depends on
+/// the element's `type`.
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct BlockAction {
+    pub r#type: String,
+    pub action_id: String,
+    pub block_id: String,
+    pub action_ts: Ts,
+    /// Buttons
+    pub value: Option<String>,
+    /// Buttons, the button's label
+    pub text: Option<Text>,
+    /// Static, external and overflow menus, radio buttons
+    pub selected_option: Option<OptionObject>,
+    /// Multi-selects and checkboxes
+    pub selected_options: Option<Vec<OptionObject>>,
+    pub selected_user: Option<UserId>,
+    pub selected_users: Option<Vec<UserId>>,
+    pub selected_conversation: Option<ChannelId>,
+    pub selected_conversations: Option<Vec<ChannelId>>,
+    pub selected_channel: Option<ChannelId>,
+    pub selected_channels: Option<Vec<ChannelId>>,
+    /// Datepickers, as YYYY-MM-DD
+    pub selected_date: Option<String>,
+}
+
+/// A modal or App Home view, as Slack sends it back.
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct ViewPayload {
+    pub id: String,
+    pub team_id: TeamId,
+    /// `modal` or `home`
+    pub r#type: String,
+    #[serde(default)]
+    pub callback_id: String,
+    #[serde(default)]
+    pub private_metadata: String,
+    pub external_id: Option<String>,
+    /// Pass this to views.update to make sure nobody else changed the view first.
+    pub hash: String,
+    pub title: Option<Text>,
+    #[serde(default)]
+    pub blocks: Vec<Value>,
+    #[serde(default)]
+    pub state: ViewState,
+    pub root_view_id: Option<String>,
+    pub previous_view_id: Option<String>,
+    pub app_id: Option<AppId>,
+    pub bot_id: Option<BotId>,
+}
+
+/// The values of a view's input elements.
+#[derive(Deserialize, Debug, Default, PartialEq)]
+pub struct ViewState {
+    /// By block_id, then action_id.
+    pub values: HashMap<String, HashMap<String, Value>>,
+}
+
+/// Where replies to a modal submission can be posted.
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct ResponseUrl {
+    pub block_id: String,
+    pub action_id: String,
+    pub channel_id: ChannelId,
+    pub response_url: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payload(json: &str) -> Interaction {
+        let body = format!(
+            "payload={}",
+            json.chars()
+                .map(|c| match c {
+                    '&' => "%26".to_string(),
+                    '=' => "%3D".to_string(),
+                    '+' => "%2B".to_string(),
+                    '%' => "%25".to_string(),
+                    c => c.to_string(),
+                })
+                .collect::<String>()
+        );
+        Interaction::from_form(&body).unwrap()
+    }
+
+    const TEAM_AND_USER: &str = r#"
+        "team": { "id": "T9TK3CUKW", "domain": "example" },
+        "user": { "id": "UA8RXUSPL", "username": "jtorrance", "team_id": "T9TK3CUKW" },
+        "token": "9s8d9as89d8as9d8as989""#;
+
+    #[test]
+    fn block_actions_works() {
+        let interaction = payload(&format!(
+            r#"{{
+                "type": "block_actions",
+                {},
+                "api_app_id": "AABA1ABCD",
+                "container": {{
+                    "type": "message",
+                    "message_ts": "1548261231.000200",
+                    "channel_id": "CBR2V3XEX",
+                    "is_ephemeral": false
+                }},
+                "trigger_id": "12321423423.333649436676.d8c1bb837935619ccad0f624c448ffb3",
+                "channel": {{ "id": "CBR2V3XEX", "name": "review-updates" }},
+                "message": {{ "type": "message", "text": "Publish?" }},
+                "response_url": "https://hooks.slack.com/actions/AABA1ABCD/1232321423432/D09sSasdasdAS9091209",
+                "actions": [
+                    {{
+                        "action_id": "WaXA",
+                        "block_id": "=qXel",
+                        "text": {{ "type": "plain_text", "text": "View", "emoji": true }},
+                        "value": "click_me_123",
+                        "type": "button",
+                        "action_ts": "1548426417.840180"
+                    }},
+                    {{
+                        "action_id": "pick",
+                        "block_id": "picker",
+                        "selected_option": {{
+                            "text": {{ "type": "plain_text", "text": "Maru" }},
+                            "value": "maru"
+                        }},
+                        "type": "static_select",
+                        "action_ts": "1548426417.840181"
+                    }}
+                ]
+            }}"#,
+            TEAM_AND_USER
+        ));
+        match interaction {
+            Interaction::BlockActions {
+                user,
+                container,
+                actions,
+                response_url,
+                ..
+            } => {
+                assert_eq!("UA8RXUSPL", user.id);
+                assert_eq!(
+                    Container::Message {
+                        message_ts: "1548261231.000200".parse().unwrap(),
+                        channel_id: "CBR2V3XEX".into(),
+                        is_ephemeral: false,
+                    },
+                    container
+                );
+                assert!(response_url.is_some());
+                assert_eq!(Some("click_me_123"), actions[0].value.as_deref());
+                assert_eq!("=qXel", actions[0].block_id);
+                assert_eq!("maru", actions[1].selected_option.as_ref().unwrap().value);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    const VIEW: &str = r#"{
+        "id": "VNHU13V36",
+        "team_id": "T9TK3CUKW",
+        "type": "modal",
+        "callback_id": "feedback",
+        "private_metadata": "shhh-its-secret",
+        "hash": "156663117.cd33ad1f",
+        "title": { "type": "plain_text", "text": "Feedback" },
+        "blocks": [],
+        "state": {
+            "values": {
+                "multi-line": {
+                    "ml-value": { "type": "plain_text_input", "value": "This is my example inputted value" }
+                }
+            }
+        },
+        "root_view_id": "VNHU13V36",
+        "previous_view_id": null,
+        "app_id": "AAD4NA4NA",
+        "bot_id": "BA13894H"
+    }"#;
+
+    #[test]
+    fn view_submission_works() {
+        let interaction = payload(&format!(
+            r#"{{
+                "type": "view_submission",
+                {},
+                "api_app_id": "AAD4NA4NA",
+                "trigger_id": "12466734323.1395872398",
+                "view": {}
+            }}"#,
+            TEAM_AND_USER, VIEW
+        ));
+        match interaction {
+            Interaction::ViewSubmission {
+                view,
+                response_urls,
+                ..
+            } => {
+                assert_eq!("feedback", view.callback_id);
+                assert_eq!("shhh-its-secret", view.private_metadata);
+                assert_eq!(
+                    "This is my example inputted value",
+                    view.state.values["multi-line"]["ml-value"]["value"]
+                );
+                assert!(response_urls.is_empty());
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+
+        let interaction = payload(&format!(
+            r#"{{
+                "type": "view_closed",
+                {},
+                "api_app_id": "AAD4NA4NA",
+                "view": {},
+                "is_cleared": false
+            }}"#,
+            TEAM_AND_USER, VIEW
+        ));
+        assert_eq!("view_closed", interaction.interaction_type());
+    }
+
+    #[test]
+    fn shortcuts_work() {
+        let interaction = payload(&format!(
+            r#"{{
+                "type": "shortcut",
+                {},
+                "callback_id": "shortcut_create_task",
+                "trigger_id": "944799105734.773906753841.38b5894552bdd4a780554ee59d1f3638",
+                "action_ts": "1581106241.371594"
+            }}"#,
+            TEAM_AND_USER
+        ));
+        match interaction {
+            Interaction::Shortcut { callback_id, .. } => {
+                assert_eq!("shortcut_create_task", callback_id)
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+
+        let interaction = payload(&format!(
+            r#"{{
+                "type": "message_action",
+                {},
+                "callback_id": "add_note",
+                "trigger_id": "13345224609.738474920.8088930838d88f008e0",
+                "action_ts": "1481579588.685999",
+                "channel": {{ "id": "D0LFFBKLZ", "name": "cats" }},
+                "message": {{ "type": "message", "user": "U0D15K92L", "ts": "1481579588.685999", "text": "Can I get a note?" }},
+                "message_ts": "1481579588.685999",
+                "response_url": "https://hooks.slack.com/app-actions/T0MJR11A4/21974584944/yk1S9ndf35Q1flupVG5JbpM6"
+            }}"#,
+            TEAM_AND_USER
+        ));
+        match interaction {
+            Interaction::MessageAction {
+                channel,
+                message_ts,
+                ..
+            } => {
+                assert_eq!("D0LFFBKLZ", channel.id);
+                assert_eq!("1481579588.685999", message_ts);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+
+        match payload(r#"{"type": "workflow_step_edit", "callback_id": "x"}"#) {
+            Interaction::Unknown { r#type, raw } => {
+                assert_eq!("workflow_step_edit", r#type);
+                assert_eq!("x", raw["callback_id"]);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
@@ -1,5 +1,19 @@
+pub mod blocks;
 mod client;
-mod events;
+pub mod commands;
+mod error;
+pub mod events;
+pub mod form;
+pub mod forwarded;
+mod ids;
+pub mod interactions;
+pub mod router;
+pub mod secrets;
+mod ts;
+pub mod verify;
 
 pub use client::*;
+pub use error::{ApiErrorCode, Error, Result};
 pub use events::{InnerEvent, OuterEvent};
+pub use ids::{AppId, BotId, ChannelId, EventId, TeamId, UserId};
+pub use ts::{BeforeEpochError, ParseTsError, Ts};
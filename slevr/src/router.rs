@@ -0,0 +1,394 @@
+//! Routes incoming events to the handlers registered for them.
+//!
+//! ```no_run
+//! use slevr::chat::post_message::ChatMessage;
+//! use slevr::router::{Filter, Router};
+//! use slevr::SlackApiClient;
+//!
+//! let router = Router::new(SlackApiClient::new("xoxb-token")).on(
+//!     Filter::message(r"^ping$").channel_type("im"),
+//!     |ctx, _event| async move {
+//!         ctx.client
+//!             .chat_post_message(ChatMessage {
+//!                 channel: ctx.channel.clone().unwrap(),
+//!                 text: "pong".to_string(),
+//!                 ..Default::default()
+//!             })
+//!             .await?;
+//!         Ok(())
+//!     },
+//! );
+//! ```
+use crate::events::{MessageSubtype, UserMessage};
+use crate::{AppId, ChannelId, EventId, InnerEvent, OuterEvent, SlackApiClient, TeamId, UserId};
+use futures::future::BoxFuture;
+use log::debug;
+use regex::Regex;
+use std::future::Future;
+use std::sync::Arc;
+
+pub type HandlerError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+type Handler = Box<
+    dyn Fn(Context, Arc<InnerEvent>) -> BoxFuture<'static, Result<(), HandlerError>> + Send + Sync,
+>;
+
+/// Everything a handler gets besides the event itself.
+#[derive(Clone)]
+pub struct Context {
+    pub client: Arc<SlackApiClient>,
+    pub team_id: TeamId,
+    pub api_app_id: AppId,
+    pub event_id: EventId,
+    pub event_time: u64,
+    pub authed_users: Vec<UserId>,
+    /// Set for messages and mentions
+    pub channel: Option<ChannelId>,
+    /// Who sent the message or mention
+    pub user: Option<UserId>,
+    pub text: Option<String>,
+    /// Capture groups from the route's pattern, 0 being the whole match.
+    pub captures: Vec<Option<String>>,
+}
+
+/// Which events a handler wants. Everything set has to match.
+#[derive(Debug, Clone, Default)]
+pub struct Filter {
+    event_type: Option<String>,
+    channel_type: Option<String>,
+    pattern: Option<Regex>,
+}
+
+impl Filter {
+    /// Every event.
+    pub fn any() -> Self {
+        Filter::default()
+    }
+
+    /// Events with this `type`, like `reaction_added`.
+    pub fn event(event_type: &str) -> Self {
+        Filter {
+            event_type: Some(event_type.to_string()),
+            ..Filter::default()
+        }
+    }
+
+    /// Messages from people whose text matches `pattern`.
+    ///
+    /// Panics if `pattern` isn't a valid regex.
+    pub fn message(pattern: &str) -> Self {
+        Filter::event("message").pattern(pattern)
+    }
+
+    /// Messages that @mention your app.
+    pub fn mention() -> Self {
+        Filter::event("app_mention")
+    }
+
+    /// Only messages in this kind of channel: channel, group, im or mpim.
+    pub fn channel_type(mut self, channel_type: &str) -> Self {
+        self.channel_type = Some(channel_type.to_string());
+        self
+    }
+
+    /// Only messages and mentions whose text matches `pattern`.
+    ///
+    /// Panics if `pattern` isn't a valid regex.
+    pub fn pattern(mut self, pattern: &str) -> Self {
+        self.pattern = Some(Regex::new(pattern).expect("route pattern should be a valid regex"));
+        self
+    }
+
+    /// `None` if the event doesn't match, otherwise the pattern's captures.
+    fn captures(&self, event: &InnerEvent) -> Option<Vec<Option<String>>> {
+        if let Some(event_type) = &self.event_type {
+            if event_type != event.event_type() {
+                return None;
+            }
+        }
+        if let Some(channel_type) = &self.channel_type {
+            match event {
+                InnerEvent::Message {
+                    channel_type: actual,
+                    ..
+                } if actual == channel_type => (),
+                _ => return None,
+            }
+        }
+        match &self.pattern {
+            None => Some(vec![]),
+            Some(pattern) => {
+                let captures = pattern.captures(message_parts(event)?.2)?;
+                Some(
+                    captures
+                        .iter()
+                        .map(|group| group.map(|group| group.as_str().to_string()))
+                        .collect(),
+                )
+            }
+        }
+    }
+}
+
+/// The channel, sender and text of anything a person said. Bot messages are left out so
+/// a bot can't end up answering itself.
+fn message_parts(event: &InnerEvent) -> Option<(&ChannelId, &UserId, &str)> {
+    match event {
+        InnerEvent::AppMention {
+            channel,
+            user,
+            text,
+            ..
+        } => Some((channel, user, text)),
+        InnerEvent::Message {
+            channel,
+            subtype:
+                MessageSubtype::Plain(UserMessage {
+                    user,
+                    text,
+                    bot_id: None,
+                    ..
+                }),
+            ..
+        }
+        | InnerEvent::Message {
+            channel,
+            subtype: MessageSubtype::ThreadBroadcast { user, text, .. },
+            ..
+        }
+        | InnerEvent::Message {
+            channel,
+            subtype: MessageSubtype::FileShare { user, text, .. },
+            ..
+        }
+        | InnerEvent::Message {
+            channel,
+            subtype: MessageSubtype::MeMessage { user, text },
+            ..
+        } => Some((channel, user, text)),
+        _ => None,
+    }
+}
+
+/// Calls every handler whose filter matches an event.
+pub struct Router {
+    client: Arc<SlackApiClient>,
+    routes: Vec<(Filter, Handler)>,
+}
+
+impl Router {
+    /// Takes the client, or an `Arc` of one that outlives the router so its rate limit
+    /// budget carries over to the next event.
+    pub fn new(client: impl Into<Arc<SlackApiClient>>) -> Self {
+        Router {
+            client: client.into(),
+            routes: vec![],
+        }
+    }
+
+    /// Registers `handler` for events matching `filter`. Handlers run in the order they
+    /// were registered.
+    pub fn on<F, Fut>(mut self, filter: Filter, handler: F) -> Self
+    where
+        F: Fn(Context, Arc<InnerEvent>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), HandlerError>> + Send + 'static,
+    {
+        self.routes.push((
+            filter,
+            Box::new(move |ctx, event| Box::pin(handler(ctx, event))),
+        ));
+        self
+    }
+
+    pub fn on_event<F, Fut>(self, event_type: &str, handler: F) -> Self
+    where
+        F: Fn(Context, Arc<InnerEvent>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), HandlerError>> + Send + 'static,
+    {
+        self.on(Filter::event(event_type), handler)
+    }
+
+    pub fn on_message<F, Fut>(self, pattern: &str, handler: F) -> Self
+    where
+        F: Fn(Context, Arc<InnerEvent>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), HandlerError>> + Send + 'static,
+    {
+        self.on(Filter::message(pattern), handler)
+    }
+
+    pub fn on_mention<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(Context, Arc<InnerEvent>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), HandlerError>> + Send + 'static,
+    {
+        self.on(Filter::mention(), handler)
+    }
+
+    pub fn on_any<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(Context, Arc<InnerEvent>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), HandlerError>> + Send + 'static,
+    {
+        self.on(Filter::any(), handler)
+    }
+
+    /// Runs the handlers for an event callback, returning how many ran. Every matching
+    /// handler runs even if an earlier one failed, and all their errors come back together.
+    /// Anything that isn't an event callback is ignored.
+    pub async fn dispatch(&self, event: OuterEvent) -> Result<usize, Vec<HandlerError>> {
+        let (team_id, api_app_id, event, authed_users, event_id, event_time) = match event {
+            OuterEvent::EventCallback {
+                team_id,
+                api_app_id,
+                event,
+                authed_users,
+                event_id,
+                event_time,
+                ..
+            } => (
+                team_id,
+                api_app_id,
+                event,
+                authed_users,
+                event_id,
+                event_time,
+            ),
+            _ => return Ok(0),
+        };
+        let parts = message_parts(&event);
+        let context = Context {
+            client: self.client.clone(),
+            team_id,
+            api_app_id,
+            event_id,
+            event_time,
+            authed_users: authed_users.unwrap_or_default(),
+            channel: parts.map(|(channel, _, _)| channel.clone()),
+            user: parts.map(|(_, user, _)| user.clone()),
+            text: parts.map(|(_, _, text)| text.to_string()),
+            captures: vec![],
+        };
+        let event = Arc::new(event);
+
+        let mut handled = 0;
+        let mut errors = vec![];
+        for (filter, handler) in &self.routes {
+            if let Some(captures) = filter.captures(&event) {
+                handled += 1;
+                let context = Context {
+                    captures,
+                    ..context.clone()
+                };
+                if let Err(err) = handler(context, event.clone()).await {
+                    debug!("Handler for {} failed - {}", event.event_type(), err);
+                    errors.push(err);
+                }
+            }
+        }
+        if errors.is_empty() {
+            Ok(handled)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    fn callback(event: &str) -> OuterEvent {
+        serde_json::from_str(&format!(
+            r#"{{
+                "token": "XXYYZZ",
+                "team_id": "TXXXXXXXX",
+                "api_app_id": "AXXXXXXXXX",
+                "event": {},
+                "type": "event_callback",
+                "event_id": "Ev08MFMKH6",
+                "event_time": 1234567890
+            }}"#,
+            event
+        ))
+        .unwrap()
+    }
+
+    const ECHO_ALL: &str = r#"{
+        "type": "message",
+        "channel": "D024BE91L",
+        "user": "U2147483697",
+        "text": "echo all",
+        "ts": "1355517523.000005",
+        "event_ts": "1355517523.000005",
+        "channel_type": "im"
+    }"#;
+
+    fn recording(
+        seen: &Arc<Mutex<Vec<String>>>,
+        name: &'static str,
+    ) -> impl Fn(Context, Arc<InnerEvent>) -> BoxFuture<'static, Result<(), HandlerError>> {
+        let seen = seen.clone();
+        move |ctx, event| {
+            seen.lock().unwrap().push(format!(
+                "{} {} {:?} {:?}",
+                name,
+                event.event_type(),
+                ctx.user,
+                ctx.captures
+            ));
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    #[tokio::test]
+    async fn router_works() {
+        let seen = Arc::new(Mutex::new(vec![]));
+        let router = Router::new(SlackApiClient::new("xoxb"))
+            .on(
+                Filter::message(r"^echo (all|none)$").channel_type("im"),
+                recording(&seen, "echo"),
+            )
+            .on(
+                Filter::message(r"^echo").channel_type("channel"),
+                recording(&seen, "channel"),
+            )
+            .on_event("reaction_added", recording(&seen, "reaction"))
+            .on_any(recording(&seen, "any"));
+
+        assert_eq!(
+            Ok(2),
+            router.dispatch(callback(ECHO_ALL)).await.map_err(|_| ())
+        );
+        assert_eq!(
+            vec![
+                "echo message Some(UserId(\"U2147483697\")) [Some(\"echo all\"), Some(\"all\")]",
+                "any message Some(UserId(\"U2147483697\")) []",
+            ],
+            *seen.lock().unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn errors_are_collected() {
+        let router = Router::new(SlackApiClient::new("xoxb"))
+            .on_any(|_, _| async { Err(HandlerError::from("first")) })
+            .on_any(|_, _| async { Ok(()) })
+            .on_any(|_, _| async { Err(HandlerError::from("second")) });
+        let errors = router.dispatch(callback(ECHO_ALL)).await.unwrap_err();
+        assert_eq!(
+            vec!["first", "second"],
+            errors.iter().map(|err| err.to_string()).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn other_outer_events_are_ignored() {
+        let router = Router::new(SlackApiClient::new("xoxb")).on_any(|_, _| async { Ok(()) });
+        let challenge = OuterEvent::UrlVerification {
+            token: "t".to_string(),
+            challenge: "c".to_string(),
+        };
+        assert_eq!(Ok(0), router.dispatch(challenge).await.map_err(|_| ()));
+    }
+}
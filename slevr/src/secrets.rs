@@ -0,0 +1,282 @@
+//! Where signing secrets and tokens come from.
+//!
+//! Secrets are looked up by a short name like `slack_signing_secret`, and each provider
+//! decides what that name means for its backend.
+use async_trait::async_trait;
+use log::debug;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// Picks the provider in [`from_env`].
+pub const PROVIDER_VAR: &str = "SLEVR_SECRETS";
+
+#[derive(Debug)]
+pub enum SecretError {
+    /// The provider has nothing called this.
+    NotFound(String),
+    /// The provider is there but couldn't be read.
+    Unavailable { name: String, reason: String },
+    /// `SLEVR_SECRETS` named a provider we don't know, or one that wasn't compiled in.
+    UnknownProvider(String),
+}
+
+impl fmt::Display for SecretError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SecretError::NotFound(name) => write!(f, "secret {} not found", name),
+            SecretError::Unavailable { name, reason } => {
+                write!(f, "couldn't read secret {} - {}", name, reason)
+            }
+            SecretError::UnknownProvider(provider) => {
+                write!(f, "unknown secret provider {:?}", provider)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SecretError {}
+
+#[async_trait]
+pub trait SecretProvider: Send + Sync {
+    async fn secret(&self, name: &str) -> Result<String, SecretError>;
+}
+
+/// Reads `slack_signing_secret` from `SLACK_SIGNING_SECRET`.
+#[derive(Debug, Default)]
+pub struct EnvSecrets;
+
+impl EnvSecrets {
+    fn var(name: &str) -> String {
+        name.to_uppercase().replace(&['-', '.'][..], "_")
+    }
+}
+
+#[async_trait]
+impl SecretProvider for EnvSecrets {
+    async fn secret(&self, name: &str) -> Result<String, SecretError> {
+        std::env::var(EnvSecrets::var(name)).map_err(|_| SecretError::NotFound(name.to_string()))
+    }
+}
+
+/// Reads `slack_signing_secret` from `<dir>/slack_signing_secret`, trimming the trailing
+/// newline editors like to leave behind.
+#[derive(Debug)]
+pub struct FileSecrets {
+    dir: PathBuf,
+}
+
+impl FileSecrets {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        FileSecrets { dir: dir.into() }
+    }
+}
+
+#[async_trait]
+impl SecretProvider for FileSecrets {
+    async fn secret(&self, name: &str) -> Result<String, SecretError> {
+        let path = self.dir.join(name);
+        match tokio::fs::read_to_string(&path).await {
+            Ok(secret) => Ok(secret.trim_end().to_string()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                Err(SecretError::NotFound(name.to_string()))
+            }
+            Err(err) => Err(SecretError::Unavailable {
+                name: name.to_string(),
+                reason: format!("{}: {}", path.display(), err),
+            }),
+        }
+    }
+}
+
+/// Reads secrets from AWS Secrets Manager, in whatever region the environment says we're in.
+///
+/// The secret id for `slack_signing_secret` is whatever was registered with
+/// [`SecretsManagerSecrets::with_id`], then the `SLACK_SIGNING_SECRET_ID` environment
+/// variable, then the name itself.
+#[cfg(feature = "secrets-manager")]
+pub struct SecretsManagerSecrets {
+    client: rusoto_secretsmanager::SecretsManagerClient,
+    ids: HashMap<String, String>,
+}
+
+#[cfg(feature = "secrets-manager")]
+impl SecretsManagerSecrets {
+    pub fn new() -> Self {
+        SecretsManagerSecrets::with_region(rusoto_core::Region::default())
+    }
+
+    pub fn with_region(region: rusoto_core::Region) -> Self {
+        SecretsManagerSecrets {
+            client: rusoto_secretsmanager::SecretsManagerClient::new(region),
+            ids: HashMap::new(),
+        }
+    }
+
+    pub fn with_id(mut self, name: &str, secret_id: impl Into<String>) -> Self {
+        self.ids.insert(name.to_string(), secret_id.into());
+        self
+    }
+
+    fn secret_id(&self, name: &str) -> String {
+        self.ids
+            .get(name)
+            .cloned()
+            .or_else(|| std::env::var(format!("{}_ID", EnvSecrets::var(name))).ok())
+            .unwrap_or_else(|| name.to_string())
+    }
+}
+
+#[cfg(feature = "secrets-manager")]
+impl Default for SecretsManagerSecrets {
+    fn default() -> Self {
+        SecretsManagerSecrets::new()
+    }
+}
+
+#[cfg(feature = "secrets-manager")]
+#[async_trait]
+impl SecretProvider for SecretsManagerSecrets {
+    async fn secret(&self, name: &str) -> Result<String, SecretError> {
+        use rusoto_secretsmanager::{GetSecretValueError, GetSecretValueRequest, SecretsManager};
+
+        let secret_id = self.secret_id(name);
+        let response = self
+            .client
+            .get_secret_value(GetSecretValueRequest {
+                secret_id,
+                version_id: None,
+                version_stage: None,
+            })
+            .await
+            .map_err(|err| match err {
+                rusoto_core::RusotoError::Service(GetSecretValueError::ResourceNotFound(_)) => {
+                    SecretError::NotFound(name.to_string())
+                }
+                err => SecretError::Unavailable {
+                    name: name.to_string(),
+                    reason: err.to_string(),
+                },
+            })?;
+        response
+            .secret_string
+            .ok_or_else(|| SecretError::Unavailable {
+                name: name.to_string(),
+                reason: "secret has no string value".to_string(),
+            })
+    }
+}
+
+/// Remembers every secret it's handed out, so a warm Lambda only goes to the backend once.
+pub struct CachedSecrets {
+    inner: Box<dyn SecretProvider>,
+    cache: Mutex<HashMap<String, String>>,
+}
+
+impl CachedSecrets {
+    pub fn new(inner: impl SecretProvider + 'static) -> Self {
+        CachedSecrets {
+            inner: Box::new(inner),
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl SecretProvider for CachedSecrets {
+    async fn secret(&self, name: &str) -> Result<String, SecretError> {
+        if let Some(secret) = self.cache.lock().unwrap().get(name) {
+            return Ok(secret.clone());
+        }
+        // Not holding the lock while we fetch means two cold callers may both fetch, which
+        // is cheaper than making every warm caller wait on a slow one.
+        let secret = self.inner.secret(name).await?;
+        self.cache
+            .lock()
+            .unwrap()
+            .insert(name.to_string(), secret.clone());
+        Ok(secret)
+    }
+}
+
+impl fmt::Debug for CachedSecrets {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedSecrets").finish()
+    }
+}
+
+/// Builds the provider named by `SLEVR_SECRETS`, wrapped in a cache.
+///
+/// * unset or `env` - [`EnvSecrets`]
+/// * `file:<dir>` - [`FileSecrets`]
+/// * `secrets-manager` - [`SecretsManagerSecrets`], with the `secrets-manager` feature
+pub fn from_env() -> Result<CachedSecrets, SecretError> {
+    let provider = std::env::var(PROVIDER_VAR).unwrap_or_else(|_| "env".to_string());
+    debug!("Reading secrets from {}", provider);
+    match &provider[..] {
+        "env" => Ok(CachedSecrets::new(EnvSecrets)),
+        #[cfg(feature = "secrets-manager")]
+        "secrets-manager" => Ok(CachedSecrets::new(SecretsManagerSecrets::new())),
+        other if other.starts_with("file:") => Ok(CachedSecrets::new(FileSecrets::new(
+            &other["file:".len()..],
+        ))),
+        other => Err(SecretError::UnknownProvider(other.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn env_secrets_works() {
+        std::env::set_var("SLEVR_TEST_ENV_SECRET", "shh");
+        assert_eq!(
+            "shh",
+            EnvSecrets.secret("slevr_test_env-secret").await.unwrap()
+        );
+        match EnvSecrets.secret("slevr_test_missing").await {
+            Err(SecretError::NotFound(name)) => assert_eq!("slevr_test_missing", name),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn file_secrets_works() {
+        let dir = std::env::temp_dir().join(format!("slevr-secrets-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("slack_signing_secret"), "shh\n").unwrap();
+
+        let secrets = FileSecrets::new(&dir);
+        assert_eq!("shh", secrets.secret("slack_signing_secret").await.unwrap());
+        assert!(matches!(
+            secrets.secret("slack_bot_token").await,
+            Err(SecretError::NotFound(_))
+        ));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    struct Counting(Arc<AtomicUsize>);
+
+    #[async_trait]
+    impl SecretProvider for Counting {
+        async fn secret(&self, name: &str) -> Result<String, SecretError> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Ok(format!("{}-value", name))
+        }
+    }
+
+    #[tokio::test]
+    async fn cached_secrets_only_fetch_once() {
+        let fetches = Arc::new(AtomicUsize::new(0));
+        let secrets = CachedSecrets::new(Counting(fetches.clone()));
+        for _ in 0..3 {
+            assert_eq!("a-value", secrets.secret("a").await.unwrap());
+        }
+        assert_eq!("b-value", secrets.secret("b").await.unwrap());
+        assert_eq!(2, fetches.load(Ordering::SeqCst));
+    }
+}
@@ -0,0 +1,267 @@
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A Slack timestamp like `1584339455.000200`.
+///
+/// Message timestamps double as ids, so they're kept exactly as Slack sent them, while
+/// ordering and equality go by the point in time they represent.
+#[derive(Clone)]
+pub struct Ts {
+    secs: u64,
+    nanos: u32,
+    raw: String,
+}
+
+impl Ts {
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// `None` if the timestamp is too far out for `SystemTime`, which a ts from a request
+    /// body can be.
+    pub fn to_system_time(&self) -> Option<SystemTime> {
+        UNIX_EPOCH.checked_add(Duration::new(self.secs, self.nanos))
+    }
+}
+
+impl Default for Ts {
+    fn default() -> Self {
+        Ts {
+            secs: 0,
+            nanos: 0,
+            raw: "0.000000".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseTsError(String);
+
+impl fmt::Display for ParseTsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid slack timestamp {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseTsError {}
+
+impl FromStr for Ts {
+    type Err = ParseTsError;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        let err = || ParseTsError(raw.to_string());
+        let mut parts = raw.splitn(2, '.');
+        let secs = parts
+            .next()
+            .filter(|secs| !secs.is_empty() && secs.bytes().all(|b| b.is_ascii_digit()))
+            .ok_or_else(err)?
+            .parse()
+            .map_err(|_| err())?;
+        let nanos = match parts.next() {
+            None => 0,
+            Some(frac) if frac.bytes().all(|b| b.is_ascii_digit()) => {
+                // Right pad (or truncate) to nine digits of nanoseconds.
+                let digits: String = frac.chars().chain("000000000".chars()).take(9).collect();
+                digits.parse().map_err(|_| err())?
+            }
+            Some(_) => return Err(err()),
+        };
+        Ok(Ts {
+            secs,
+            nanos,
+            raw: raw.to_string(),
+        })
+    }
+}
+
+/// A `SystemTime` before the unix epoch, which no Slack timestamp can represent.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BeforeEpochError;
+
+impl fmt::Display for BeforeEpochError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("time is before the unix epoch")
+    }
+}
+
+impl std::error::Error for BeforeEpochError {}
+
+impl TryFrom<SystemTime> for Ts {
+    type Error = BeforeEpochError;
+
+    fn try_from(time: SystemTime) -> Result<Self, Self::Error> {
+        let since = time
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| BeforeEpochError)?;
+        let micros = since.subsec_micros();
+        Ok(Ts {
+            secs: since.as_secs(),
+            nanos: micros * 1_000,
+            raw: format!("{}.{:06}", since.as_secs(), micros),
+        })
+    }
+}
+
+impl fmt::Display for Ts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+impl fmt::Debug for Ts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Ts({})", self.raw)
+    }
+}
+
+impl PartialEq for Ts {
+    fn eq(&self, other: &Self) -> bool {
+        (self.secs, self.nanos) == (other.secs, other.nanos)
+    }
+}
+
+impl Eq for Ts {}
+
+impl PartialEq<&str> for Ts {
+    fn eq(&self, other: &&str) -> bool {
+        other.parse::<Ts>().ok().as_ref() == Some(self)
+    }
+}
+
+impl PartialEq<Ts> for &str {
+    fn eq(&self, other: &Ts) -> bool {
+        other == self
+    }
+}
+
+impl Hash for Ts {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.secs, self.nanos).hash(state);
+    }
+}
+
+impl PartialOrd for Ts {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Ts {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.secs, self.nanos).cmp(&(other.secs, other.nanos))
+    }
+}
+
+impl Serialize for Ts {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.raw)
+    }
+}
+
+impl<'de> Deserialize<'de> for Ts {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct TsVisitor;
+
+        impl<'de> de::Visitor<'de> for TsVisitor {
+            type Value = Ts;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a slack timestamp string or number")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Ts, E> {
+                v.parse().map_err(E::custom)
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Ts, E> {
+                Ok(Ts {
+                    secs: v,
+                    nanos: 0,
+                    raw: v.to_string(),
+                })
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Ts, E> {
+                if v < 0 {
+                    return Err(E::custom("negative slack timestamp"));
+                }
+                self.visit_u64(v as u64)
+            }
+
+            // Some older payloads send ts as a JSON number, which has already lost
+            // precision by the time we see it.
+            fn visit_f64<E: de::Error>(self, v: f64) -> Result<Ts, E> {
+                format!("{:.6}", v).parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(TsVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_what_slack_sent() {
+        let ts: Ts = "1584339455.000200".parse().unwrap();
+        assert_eq!("1584339455.000200", ts.to_string());
+        assert_eq!("\"1584339455.000200\"", serde_json::to_string(&ts).unwrap());
+    }
+
+    #[test]
+    fn orders_by_time() {
+        let a: Ts = "1584339455.000200".parse().unwrap();
+        let b: Ts = "1584339455.000201".parse().unwrap();
+        let c: Ts = "1584339456.000000".parse().unwrap();
+        assert!(a < b && b < c);
+        assert_eq!(a, "1584339455.0002".parse::<Ts>().unwrap());
+    }
+
+    #[test]
+    fn converts_to_and_from_system_time() {
+        let ts: Ts = "1584339455.000200".parse().unwrap();
+        let time = ts.to_system_time().unwrap();
+        assert_eq!(UNIX_EPOCH + Duration::new(1_584_339_455, 200_000), time);
+        assert_eq!(ts, Ts::try_from(time).unwrap());
+        assert_eq!("1584339455.000200", Ts::try_from(time).unwrap().as_str());
+    }
+
+    #[test]
+    fn pre_epoch_time_is_an_error() {
+        let time = UNIX_EPOCH - Duration::from_secs(1);
+        assert_eq!(Err(BeforeEpochError), Ts::try_from(time));
+    }
+
+    #[test]
+    fn huge_ts_has_no_system_time() {
+        let ts: Ts = "18446744073709551615.999999".parse().unwrap();
+        assert_eq!(None, ts.to_system_time());
+    }
+
+    #[test]
+    fn rejects_garbage() {
+        assert!("".parse::<Ts>().is_err());
+        assert!("abc.123".parse::<Ts>().is_err());
+        assert!("123.4x".parse::<Ts>().is_err());
+        assert!(serde_json::from_str::<Ts>("\"-1.0\"").is_err());
+    }
+
+    #[test]
+    fn accepts_numbers() {
+        let ts: Ts = serde_json::from_str("1584339455").unwrap();
+        assert_eq!(ts, "1584339455.000000");
+    }
+}
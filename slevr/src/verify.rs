@@ -0,0 +1,250 @@
+//! Checks that an incoming request really came from Slack.
+//! More info - https://api.slack.com/authentication/verifying-requests-from-slack
+use hmac::{Hmac, Mac};
+use hyper::HeaderMap;
+use sha2::Sha256;
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+type HmacSha256 = Hmac<Sha256>;
+
+pub const SIGNATURE_HEADER: &str = "X-Slack-Signature";
+pub const TIMESTAMP_HEADER: &str = "X-Slack-Request-Timestamp";
+
+/// The only signature version Slack has published so far.
+const VERSION: &str = "v0";
+
+/// Why a request was turned away.
+#[derive(Debug, Clone, PartialEq)]
+pub enum VerifyError {
+    /// One of the signing headers wasn't sent, or wasn't valid text.
+    MissingHeader(&'static str),
+    /// The timestamp header wasn't a number of seconds.
+    InvalidTimestamp(String),
+    /// The timestamp is further from now than the verifier allows, which is what a replay
+    /// looks like.
+    Expired { age: Duration },
+    /// The signature wasn't `v0=` followed by hex.
+    MalformedSignature,
+    /// The signature was for a version we don't know how to check.
+    UnsupportedVersion(String),
+    /// Everything was well formed, but the signature doesn't match the body.
+    Mismatch,
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifyError::MissingHeader(header) => write!(f, "missing {} header", header),
+            VerifyError::InvalidTimestamp(ts) => write!(f, "invalid request timestamp {:?}", ts),
+            VerifyError::Expired { age } => write!(f, "request is {:?} old", age),
+            VerifyError::MalformedSignature => write!(f, "malformed signature"),
+            VerifyError::UnsupportedVersion(version) => {
+                write!(f, "unsupported signature version {:?}", version)
+            }
+            VerifyError::Mismatch => write!(f, "signature mismatch"),
+        }
+    }
+}
+
+impl std::error::Error for VerifyError {}
+
+/// Verifies the `X-Slack-Signature` Slack sends with every Events API, slash command
+/// and interactivity request.
+#[derive(Clone)]
+pub struct SignatureVerifier {
+    secret: Vec<u8>,
+    max_age: Duration,
+}
+
+impl SignatureVerifier {
+    /// `signing_secret` is the Signing Secret from your app's Basic Information page.
+    pub fn new(signing_secret: impl Into<Vec<u8>>) -> Self {
+        SignatureVerifier {
+            secret: signing_secret.into(),
+            max_age: Duration::from_secs(5 * 60),
+        }
+    }
+
+    /// How far a request's timestamp can be from now, five minutes by default.
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// Verifies a request using its signing headers.
+    pub fn verify_headers(&self, headers: &HeaderMap, body: &[u8]) -> Result<(), VerifyError> {
+        let header = |name: &'static str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .ok_or(VerifyError::MissingHeader(name))
+        };
+        self.verify(header(TIMESTAMP_HEADER)?, body, header(SIGNATURE_HEADER)?)
+    }
+
+    /// Verifies a request given the raw `X-Slack-Request-Timestamp` and
+    /// `X-Slack-Signature` values.
+    pub fn verify(&self, timestamp: &str, body: &[u8], signature: &str) -> Result<(), VerifyError> {
+        self.verify_at(SystemTime::now(), timestamp, body, signature)
+    }
+
+    fn verify_at(
+        &self,
+        now: SystemTime,
+        timestamp: &str,
+        body: &[u8],
+        signature: &str,
+    ) -> Result<(), VerifyError> {
+        let invalid = || VerifyError::InvalidTimestamp(timestamp.to_string());
+        // The header isn't authenticated yet, so it mustn't be able to overflow SystemTime.
+        let sent = timestamp
+            .trim()
+            .parse::<u64>()
+            .ok()
+            .and_then(|secs| UNIX_EPOCH.checked_add(Duration::from_secs(secs)))
+            .ok_or_else(invalid)?;
+        // Clock skew can put the timestamp a little in the future, but not by more than
+        // the window.
+        let age = now
+            .duration_since(sent)
+            .unwrap_or_else(|err| err.duration());
+        if age > self.max_age {
+            return Err(VerifyError::Expired { age });
+        }
+
+        let eq = signature.find('=').ok_or(VerifyError::MalformedSignature)?;
+        let (version, hex_sig) = (&signature[..eq], &signature[eq + 1..]);
+        if version != VERSION {
+            return Err(VerifyError::UnsupportedVersion(version.to_string()));
+        }
+        let sig = hex::decode(hex_sig).map_err(|_| VerifyError::MalformedSignature)?;
+
+        // Mac::verify compares in constant time.
+        self.mac(timestamp, body)
+            .verify(&sig)
+            .map_err(|_| VerifyError::Mismatch)
+    }
+
+    /// The `X-Slack-Signature` Slack would send with `body`, for testing handlers.
+    pub fn sign(&self, timestamp: &str, body: &[u8]) -> String {
+        format!(
+            "{}={}",
+            VERSION,
+            hex::encode(self.mac(timestamp, body).result().code())
+        )
+    }
+
+    fn mac(&self, timestamp: &str, body: &[u8]) -> HmacSha256 {
+        let mut mac = HmacSha256::new_varkey(&self.secret).expect("hmac accepts keys of any size");
+        mac.input(VERSION.as_bytes());
+        mac.input(b":");
+        mac.input(timestamp.as_bytes());
+        mac.input(b":");
+        mac.input(body);
+        mac
+    }
+}
+
+impl fmt::Debug for SignatureVerifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SignatureVerifier")
+            .field("max_age", &self.max_age)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hyper::header::HeaderValue;
+
+    // The example from Slack's docs.
+    const SECRET: &str = "8f742231b10e8888abcd99yyyzzz85a5";
+    const TIMESTAMP: &str = "1531420618";
+    const BODY: &str = "token=xyzz0WbapA4vBCDEFasx0q6G&team_id=T1DC2JH3J&team_domain=testteamnow&channel_id=G8PSS9T3V&channel_name=foobar&user_id=U2CERLKJA&user_name=roadrunner&command=%2Fwebhook-collect&text=&response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2FT1DC2JH3J%2F397700885554%2F96rGlfmibIGlgcZRskXaIFfN&trigger_id=398738663015.47445629121.803a0bc887a14d10d2c447fce8b6703c";
+    const SIGNATURE: &str = "v0=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503";
+
+    fn at(secs: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(secs)
+    }
+
+    fn verify(timestamp: &str, signature: &str, now: SystemTime) -> Result<(), VerifyError> {
+        SignatureVerifier::new(SECRET).verify_at(now, timestamp, BODY.as_bytes(), signature)
+    }
+
+    #[test]
+    fn verify_works() {
+        assert_eq!(Ok(()), verify(TIMESTAMP, SIGNATURE, at(1_531_420_618 + 10)));
+    }
+
+    #[test]
+    fn sign_matches_slack() {
+        assert_eq!(
+            SIGNATURE,
+            SignatureVerifier::new(SECRET).sign(TIMESTAMP, BODY.as_bytes())
+        );
+    }
+
+    #[test]
+    fn tampered_body_mismatches() {
+        let result = SignatureVerifier::new(SECRET).verify_at(
+            at(1_531_420_618),
+            TIMESTAMP,
+            b"token=something-else",
+            SIGNATURE,
+        );
+        assert_eq!(Err(VerifyError::Mismatch), result);
+    }
+
+    #[test]
+    fn old_requests_expire() {
+        assert_eq!(
+            Err(VerifyError::Expired {
+                age: Duration::from_secs(301)
+            }),
+            verify(TIMESTAMP, SIGNATURE, at(1_531_420_618 + 301))
+        );
+        assert_eq!(
+            Err(VerifyError::Expired {
+                age: Duration::from_secs(600)
+            }),
+            verify(TIMESTAMP, SIGNATURE, at(1_531_420_618 - 600))
+        );
+    }
+
+    #[test]
+    fn bad_signatures_are_errors_not_panics() {
+        let now = at(1_531_420_618);
+        assert_eq!(
+            Err(VerifyError::MalformedSignature),
+            verify(TIMESTAMP, "", now)
+        );
+        assert_eq!(
+            Err(VerifyError::MalformedSignature),
+            verify(TIMESTAMP, "v0=zz", now)
+        );
+        assert_eq!(
+            Err(VerifyError::UnsupportedVersion("v1".to_string())),
+            verify(TIMESTAMP, "v1=abcd", now)
+        );
+        assert_eq!(
+            Err(VerifyError::InvalidTimestamp("soon".to_string())),
+            verify("soon", SIGNATURE, now)
+        );
+        assert_eq!(
+            Err(VerifyError::InvalidTimestamp(u64::MAX.to_string())),
+            verify(&u64::MAX.to_string(), SIGNATURE, now)
+        );
+    }
+
+    #[test]
+    fn missing_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(TIMESTAMP_HEADER, HeaderValue::from_static(TIMESTAMP));
+        assert_eq!(
+            Err(VerifyError::MissingHeader(SIGNATURE_HEADER)),
+            SignatureVerifier::new(SECRET).verify_headers(&headers, BODY.as_bytes())
+        );
+    }
+}